@@ -0,0 +1,29 @@
+use std::{io::Write, thread, time::Duration};
+
+use framebrush::{AnsiPresenter, Canvas, Color, IoSink};
+
+const BUF_WIDTH: usize = 64;
+const BUF_HEIGHT: usize = 32;
+
+fn main() {
+    let mut buf = [0u32; BUF_WIDTH * BUF_HEIGHT];
+    let mut presenter = AnsiPresenter::new();
+    let mut stdout = IoSink(std::io::stdout().lock());
+
+    // Clear the screen and hide the cursor
+    stdout.0.write_all(b"\x1b[2J\x1b[?25l").unwrap();
+    for frame in 0..180 {
+        let mut canvas = Canvas::new(&mut buf, (BUF_WIDTH, BUF_HEIGHT), (BUF_WIDTH, BUF_HEIGHT));
+        let mut canvas = canvas.borrowed();
+        canvas.fill(0x101018);
+        let x = frame % 96 - 16;
+        let hue = (frame * 4 % 360) as f32;
+        canvas.rect(x, 8, 16, 16, &Color::from_hsv(hue, 0.8, 1.));
+        canvas.line(0, 31, 63, 0, &Color::WHITE);
+
+        presenter.present(&mut stdout, &canvas.surface()).unwrap();
+        stdout.0.flush().unwrap();
+        thread::sleep(Duration::from_millis(16));
+    }
+    stdout.0.write_all(b"\x1b[?25h\n").unwrap();
+}
@@ -69,7 +69,7 @@ fn main() {
             }
         }
 
-        window.update_with_buffer(&mut buf, width, height).unwrap();
+        window.update_with_buffer(&buf, width, height).unwrap();
     }
 }
 
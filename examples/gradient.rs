@@ -0,0 +1,51 @@
+use framebrush::{Canvas, Color, ColorSpace, Extend, Gradient, Stop};
+use minifb::{Window, WindowOptions};
+
+const DEFAULT_WIDTH: usize = 800;
+const DEFAULT_HEIGHT: usize = 600;
+
+const CANVAS_WIDTH: usize = 320;
+const CANVAS_HEIGHT: usize = 240;
+
+fn main() {
+    let stops = [
+        Stop::new(0., Color::from_hex("#ff0080").unwrap()),
+        Stop::new(0.5, Color::from_hex("#ffd700").unwrap()),
+        Stop::new(1., Color::from_hex("#00bfff").unwrap()),
+    ];
+    let linear = Gradient::linear(10., 0., 150., 0., &stops).with_space(ColorSpace::Linear);
+    let oklab = Gradient::linear(170., 0., 310., 0., &stops).with_space(ColorSpace::Oklab);
+    let radial = Gradient::radial(80., 170., 30., &stops).with_extend(Extend::Reflect);
+    let conic = Gradient::conic(240., 170., 0., &stops);
+
+    let mut buf = vec![0; DEFAULT_WIDTH * DEFAULT_HEIGHT];
+    let mut window = Window::new(
+        "Gradients",
+        DEFAULT_WIDTH,
+        DEFAULT_HEIGHT,
+        WindowOptions {
+            resize: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    window.set_target_fps(144);
+    while window.is_open() {
+        let (width, height) = window.get_size();
+        buf.resize(width * height, 0);
+
+        // Begin drawing
+        let mut canvas = Canvas::new(&mut buf, (width, height), (CANVAS_WIDTH, CANVAS_HEIGHT));
+        let mut canvas = canvas.borrowed();
+        canvas.fill(0);
+        canvas.rect(10, 10, 140, 80, &linear);
+        canvas.rect(170, 10, 140, 80, &oklab);
+        canvas.rect(10, 110, 140, 120, &radial);
+        canvas.rect(170, 110, 140, 120, &conic);
+        canvas.line(10, 100, 310, 100, &linear);
+        // End drawing
+
+        window.update_with_buffer(&buf, width, height).unwrap();
+    }
+}
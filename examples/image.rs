@@ -18,75 +18,13 @@ mod image {
     ];
 }
 
-use framebrush::{Canvas, Draw};
+use framebrush::{Canvas, Image};
 use image::IMAGE;
 use minifb::{Window, WindowOptions};
-use std::marker::PhantomData;
 
 const DEFAULT_WIDTH: usize = 800;
 const DEFAULT_HEIGHT: usize = 600;
 
-// T is AsRef<[U]> instead of something simple like Vec<U> because we want ImageSource to be
-// able to both own or just hold a reference to the source data. For instance, we probably don't
-// need to own the asset data we read from the disk or from some other source, but we should own
-// the data that we get after rendering the image to our desired size. So in this example we only
-// provide a slice of `IMAGE` to ImageSource but then we render that image which owns the new rendered data
-// and that rendered data is stored in the `image_render` variable
-struct ImageSource<T: AsRef<[U]>, U: Clone> {
-    data: T,
-    width: usize,
-    _marker: PhantomData<U>, // Needed because rust
-}
-
-impl<U: Clone, T: AsRef<[U]>> ImageSource<T, U> {
-    fn new(data: T, width: usize) -> Self {
-        Self {
-            data,
-            width,
-            _marker: PhantomData,
-        }
-    }
-
-    // We panic on an empty image because we don't have access to something like U::default() so we can't fill the resulting image
-    // with some default value
-    fn render(&self, target_width: usize, target_height: usize) -> ImageSource<Vec<U>, U> {
-        // Just copy the existing data instead of using something like U::default() because we want to be as generic as possible
-        let mut render_data = vec![
-            self.data
-                .as_ref()
-                .get(0)
-                .expect("can't render empty image")
-                .clone();
-            target_width * target_height
-        ];
-
-        // Use the framebrush Canvas to resize our image correctly instead of implementing the same functionality again
-        let mut render_canvas = Canvas::new(
-            &mut render_data,
-            (target_width, target_height),
-            (self.width, self.data.as_ref().len() / self.width),
-        );
-        let mut render_canvas = render_canvas.borrowed();
-        // The imaginary canvas is the same size as our original image. We can imagine the render_data as the framebuffer
-        // of our screen and the size of our original image can be imagined as the resolution of your game, for instance.
-        // We are basically treating the rendered image as a window and using framebrush's scaling implementation to avoid
-        // wiriting the same logic again.
-        render_canvas.draw(0, 0, self);
-        ImageSource::new(render_data, target_width)
-    }
-}
-
-impl<U: Clone, T: AsRef<[U]>> Draw for ImageSource<T, U> {
-    type T = U;
-    fn draw(&self, canvas: &mut Canvas<Self::T, &mut [Self::T]>, start_x: i32, start_y: i32) {
-        for (y, strip) in self.data.as_ref().chunks(self.width).enumerate() {
-            for (x, c) in strip.iter().enumerate() {
-                canvas.put(start_x + x as i32, start_y + y as i32, c.clone());
-            }
-        }
-    }
-}
-
 fn main() {
     let mut framebuffer = vec![0; DEFAULT_WIDTH * DEFAULT_HEIGHT];
 
@@ -101,7 +39,11 @@ fn main() {
     )
     .unwrap();
 
-    let image_render = ImageSource::new(&IMAGE, 8).render(200, 300);
+    // `IMAGE` is only borrowed, the scaled copy owns its data
+    let image = Image::new(&IMAGE, 8, 8);
+    let image_render = image.scaled(200, 300);
+    // A view of the 4x4 top-right corner of the image, mirrored horizontally
+    let corner = image.region(4, 0, 4, 4).flipped_h().scaled(100, 100);
 
     window.set_target_fps(144);
     while window.is_open() {
@@ -117,6 +59,7 @@ fn main() {
         let mut canvas = canvas.borrowed();
         canvas.fill(0);
         canvas.draw(100, 100, &image_render);
+        canvas.draw(400, 100, &corner);
 
         // End drawing
         window
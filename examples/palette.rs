@@ -0,0 +1,72 @@
+use framebrush::{Canvas, Color, Cycle, Index, Palette};
+use minifb::{Window, WindowOptions};
+use std::time::Instant;
+
+const DEFAULT_WIDTH: usize = 800;
+const DEFAULT_HEIGHT: usize = 600;
+
+const CANVAS_WIDTH: usize = 160;
+const CANVAS_HEIGHT: usize = 120;
+
+fn main() {
+    // Index 0 is the background, indices 1..=32 are a rainbow that gets cycled
+    let mut colors = [Color::BLACK; 33];
+    for (i, c) in colors[1..].iter_mut().enumerate() {
+        *c = Color::from_hsv(i as f32 * 360. / 32., 0.8, 1.);
+    }
+    let palette = Palette::new(colors);
+    let cycles = [Cycle {
+        start: 1,
+        end: 32,
+        rate: 24.,
+    }];
+
+    // The scene is drawn only once into the indexed canvas, the animation comes from the palette
+    let mut indexed = vec![0u8; CANVAS_WIDTH * CANVAS_HEIGHT];
+    let mut canvas = Canvas::new(
+        &mut indexed,
+        (CANVAS_WIDTH, CANVAS_HEIGHT),
+        (CANVAS_WIDTH, CANVAS_HEIGHT),
+    );
+    let mut canvas = canvas.borrowed();
+    for i in 0..32 {
+        canvas.rect(i * 4 + 16, 20, 4, 80, &Index(i as u8 + 1));
+    }
+
+    let mut buf = vec![0; DEFAULT_WIDTH * DEFAULT_HEIGHT];
+    let mut resolved = vec![0u32; CANVAS_WIDTH * CANVAS_HEIGHT];
+    let mut window = Window::new(
+        "Palette Cycling",
+        DEFAULT_WIDTH,
+        DEFAULT_HEIGHT,
+        WindowOptions {
+            resize: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    window.set_target_fps(144);
+    let start = Instant::now();
+    while window.is_open() {
+        let (width, height) = window.get_size();
+        buf.resize(width * height, 0);
+
+        palette.resolve_cycled(
+            &indexed,
+            &mut resolved,
+            &cycles,
+            start.elapsed().as_secs_f32(),
+        );
+
+        // Scale the resolved canvas up to the window
+        let mut canvas = Canvas::new(&mut buf, (width, height), (CANVAS_WIDTH, CANVAS_HEIGHT));
+        for y in 0..CANVAS_HEIGHT {
+            for x in 0..CANVAS_WIDTH {
+                canvas.put(x as i32, y as i32, resolved[x + y * CANVAS_WIDTH]);
+            }
+        }
+
+        window.update_with_buffer(&buf, width, height).unwrap();
+    }
+}
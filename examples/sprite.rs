@@ -0,0 +1,89 @@
+use framebrush::{
+    Affine, AnimatedSprite, Canvas, Color, Gradient, Image, Sampling, SpriteSheet, Stop,
+    Transparency,
+};
+use minifb::{Window, WindowOptions};
+use std::time::Instant;
+
+const DEFAULT_WIDTH: usize = 800;
+const DEFAULT_HEIGHT: usize = 600;
+
+const CANVAS_WIDTH: usize = 200;
+const CANVAS_HEIGHT: usize = 150;
+
+const KEY: u32 = 0xff00ff;
+const FRAME: usize = 16;
+const FRAMES: usize = 4;
+
+fn main() {
+    // A sheet of 4 frames of a growing ball, the background of each frame is the color key
+    let mut sheet_data = vec![KEY; FRAME * FRAMES * FRAME];
+    for frame in 0..FRAMES {
+        let radius = 3 + frame * 2;
+        for y in 0..FRAME {
+            for x in 0..FRAME {
+                let (dx, dy) = (x as i32 - 8, y as i32 - 8);
+                if dx * dx + dy * dy <= (radius * radius) as i32 {
+                    sheet_data[frame * FRAME + x + y * FRAME * FRAMES] = 0xffd700;
+                }
+            }
+        }
+    }
+    let sheet = SpriteSheet::new(
+        Image::new(&sheet_data[..], FRAME * FRAMES, FRAME),
+        (FRAME, FRAME),
+        &[0.1, 0.1, 0.1, 0.4],
+    )
+    .with_transparency(Transparency::Key(KEY));
+
+    let pickup = Image::new(
+        [
+            0x00bfff, 0xffffff, 0x00bfff, 0xffffff, 0x00bfff, 0xffffff, 0x00bfff, 0xffffff,
+            0x00bfff,
+        ],
+        3,
+        3,
+    );
+
+    let stops = [
+        Stop::new(0., Color::from_hex("#203040").unwrap()),
+        Stop::new(1., Color::from_hex("#506080").unwrap()),
+    ];
+    let background = Gradient::linear(0., 0., 0., CANVAS_HEIGHT as f32, &stops);
+
+    let mut buf = vec![0; DEFAULT_WIDTH * DEFAULT_HEIGHT];
+    let mut window = Window::new(
+        "Sprites",
+        DEFAULT_WIDTH,
+        DEFAULT_HEIGHT,
+        WindowOptions {
+            resize: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    window.set_target_fps(144);
+    let start = Instant::now();
+    while window.is_open() {
+        let (width, height) = window.get_size();
+        buf.resize(width * height, 0);
+        let time = start.elapsed().as_secs_f32();
+
+        // Begin drawing
+        let mut canvas = Canvas::new(&mut buf, (width, height), (CANVAS_WIDTH, CANVAS_HEIGHT));
+        let mut canvas = canvas.borrowed();
+        canvas.rect(0, 0, CANVAS_WIDTH, CANVAS_HEIGHT, &background);
+        for i in 0..4 {
+            let ball = AnimatedSprite::new(&sheet, time + i as f32 * 0.25, true);
+            canvas.draw(30 + i * 40, 40, &ball);
+        }
+        let spin = Affine::translate(-1.5, -1.5)
+            .then(Affine::scale(6., 6.))
+            .then(Affine::rotate(time * 2.));
+        canvas.blit_transformed(100, 110, &pickup, spin, Sampling::Nearest);
+        // End drawing
+
+        window.update_with_buffer(&buf, width, height).unwrap();
+    }
+}
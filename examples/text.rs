@@ -0,0 +1,73 @@
+use framebrush::{Align, Canvas, RGBu32, Text, TextBox, VerticalAlign, FONT_8X8, WHITE, YELLOW};
+use minifb::{Window, WindowOptions};
+
+const DEFAULT_WIDTH: usize = 800;
+const DEFAULT_HEIGHT: usize = 600;
+
+const CANVAS_WIDTH: usize = 320;
+const CANVAS_HEIGHT: usize = 240;
+
+fn main() {
+    let mut window = Window::new(
+        "Text Example",
+        DEFAULT_WIDTH,
+        DEFAULT_HEIGHT,
+        WindowOptions {
+            resize: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    window.set_target_fps(144);
+
+    let mut buf = vec![0; DEFAULT_WIDTH * DEFAULT_HEIGHT];
+    let mut frames = 0u32;
+
+    while window.is_open() {
+        let (width, height) = window.get_size();
+        buf.resize(width * height, 0);
+
+        let mut canvas = Canvas::new(&mut buf, (width, height), (CANVAS_WIDTH, CANVAS_HEIGHT));
+        let mut canvas = canvas.borrowed();
+        canvas.fill(0);
+
+        canvas.draw(
+            8,
+            8,
+            &Text::new("framebrush", &FONT_8X8, &YELLOW).with_scale(3),
+        );
+        canvas.draw(
+            8,
+            48,
+            &Text::new(
+                "The quick brown fox\njumps over the lazy dog.\n0123456789 !?#@&",
+                &FONT_8X8,
+                &WHITE,
+            )
+            .with_spacing(1, 4),
+        );
+
+        // A button-like label, wrapped and centered in its box
+        canvas.rect(200, 110, 100, 60, &RGBu32::Rgb(40, 40, 80));
+        canvas.draw(
+            200,
+            110,
+            &TextBox::new(
+                Text::new("Centered label that wraps", &FONT_8X8, &WHITE).with_spacing(0, 2),
+                (100, 60),
+            )
+            .with_align(Align::Center, VerticalAlign::Middle)
+            .with_ellipsis("..."),
+        );
+
+        let counter = format!("frame {frames}");
+        canvas.draw(
+            8,
+            200,
+            &Text::new(&counter, &FONT_8X8, &RGBu32::Rgb(100, 200, 255)).with_scale(2),
+        );
+        frames = frames.wrapping_add(1);
+
+        window.update_with_buffer(&buf, width, height).unwrap();
+    }
+}
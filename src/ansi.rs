@@ -0,0 +1,208 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+
+use crate::Color;
+#[cfg(feature = "alloc")]
+use crate::{Image, PixelFormat, Sink};
+
+#[cfg(feature = "alloc")]
+const UPPER_HALF: &str = "\u{2580}";
+#[cfg(feature = "alloc")]
+const LOWER_HALF: &str = "\u{2584}";
+#[cfg(feature = "alloc")]
+const FULL_BLOCK: &str = "\u{2588}";
+/// Color of the missing lower half of the last row at odd heights, the default background of the terminal.
+/// Colors are at most 24 bits, so this never collides with one.
+#[cfg(feature = "alloc")]
+const DEFAULT: u32 = u32::MAX;
+
+/// Colors used by `AnsiPresenter` and `BrailleRenderer`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AnsiColors {
+    /// 24-bit colors, supported by most modern terminals
+    #[default]
+    TrueColor,
+    /// The 256-color palette of xterm, colors are mapped to the nearest color of its 6x6x6 cube or gray ramp
+    Indexed,
+}
+
+/// Returns the index of the xterm 256-color palette that is closest to `c`
+pub(crate) fn xterm_index(c: Color) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let level = |v: u8| match v {
+        0..48 => 0,
+        48..115 => 1,
+        _ => (v - 35) / 40,
+    };
+    let distance = |a: [u8; 3]| {
+        let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2);
+        d(a[0], c.r) + d(a[1], c.g) + d(a[2], c.b)
+    };
+
+    let cube = [level(c.r), level(c.g), level(c.b)];
+    let cube_color = cube.map(|i| LEVELS[i as usize]);
+    // The gray ramp goes from 8 to 238 in steps of 10
+    let average = (c.r as u32 + c.g as u32 + c.b as u32) / 3;
+    let gray = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray_value = 8 + gray * 10;
+    if distance([gray_value; 3]) < distance(cube_color) {
+        232 + gray
+    } else {
+        16 + cube[0] * 36 + cube[1] * 6 + cube[2]
+    }
+}
+
+/// Presents frames on a terminal with ANSI escape sequences.
+///
+/// Every terminal cell shows two vertically stacked pixels with the half-block characters `▀` and `▄`, so a frame of
+/// `w * h` pixels takes up `w` columns and `h / 2` rows (rounded up). The cells of the last frame are kept, and only
+/// the cells that changed since then are written again, which keeps animations fast even over slow connections.
+///
+/// A frame is written with a single call to the sink, usually `IoSink(std::io::stdout())`. The terminal is expected
+/// to show nothing else in the area of the frame, `invalidate` redraws everything after it was cleared or resized.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct AnsiPresenter {
+    colors: AnsiColors,
+    origin: (usize, usize),
+    size: (usize, usize),
+    /// (upper, lower) colors of every cell as written to the terminal, empty if everything has to be redrawn
+    cells: Vec<(u32, u32)>,
+    out: String,
+}
+
+#[cfg(feature = "alloc")]
+impl AnsiPresenter {
+    /// Creates a presenter that uses 24-bit colors and draws at the top-left corner of the terminal
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the colors of the escape sequences, `AnsiColors::TrueColor` by default
+    pub fn with_colors(self, colors: AnsiColors) -> Self {
+        Self { colors, ..self }
+    }
+
+    /// Moves the frame to the 0-based `column` and `row` of the terminal
+    pub fn with_origin(self, column: usize, row: usize) -> Self {
+        Self {
+            origin: (column, row),
+            ..self
+        }
+    }
+
+    /// Makes the next `present` write all cells again, for example after the terminal was cleared
+    pub fn invalidate(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Returns the color that is sent to the terminal for `c`, either RGB or a palette index
+    fn terminal_color(&self, c: Color) -> u32 {
+        match self.colors {
+            AnsiColors::TrueColor => c.to_u32(),
+            AnsiColors::Indexed => xterm_index(c) as u32,
+        }
+    }
+
+    /// Appends the parameters of a select graphic rendition sequence that sets the foreground (`layer` 38) or
+    /// background (`layer` 48) color
+    fn push_color(&mut self, layer: u8, color: u32) {
+        if !self.out.ends_with('[') {
+            self.out.push(';');
+        }
+        let _ = match (self.colors, color) {
+            (_, DEFAULT) => write!(self.out, "{}", layer + 1),
+            (AnsiColors::TrueColor, _) => {
+                let [_, r, g, b] = color.to_be_bytes();
+                write!(self.out, "{layer};2;{r};{g};{b}")
+            }
+            (AnsiColors::Indexed, _) => write!(self.out, "{layer};5;{color}"),
+        };
+    }
+
+    /// Writes the cells of `frame` that changed since the last frame and returns how many cells were written.
+    ///
+    /// Alpha is ignored. If the size of `frame` differs from the last frame, all of it is written.
+    pub fn present<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+        &mut self,
+        sink: &mut S,
+        frame: &Image<P, B>,
+    ) -> Result<usize, S::Error> {
+        let (w, h) = frame.size();
+        let rows = h.div_ceil(2);
+        if self.size != (w, h) {
+            self.size = (w, h);
+            self.cells.clear();
+        }
+        let redraw = self.cells.is_empty();
+        if redraw {
+            self.cells.resize(w * rows, (DEFAULT, DEFAULT));
+        }
+
+        self.out.clear();
+        let mut changed = 0;
+        // The colors and cursor position of the terminal, unknown until they are set by this frame
+        let (mut fg, mut bg) = (None, None);
+        let mut cursor = None;
+        for row in 0..rows {
+            for column in 0..w {
+                let color = |y: usize| {
+                    frame
+                        .get(column, y)
+                        .map_or(DEFAULT, |p| self.terminal_color(p.to_color()))
+                };
+                let cell = (color(row * 2), color(row * 2 + 1));
+                let i = column + row * w;
+                if !redraw && self.cells[i] == cell {
+                    continue;
+                }
+                self.cells[i] = cell;
+                changed += 1;
+
+                if cursor != Some((column, row)) {
+                    let (x, y) = (self.origin.0 + column + 1, self.origin.1 + row + 1);
+                    let _ = write!(self.out, "\x1b[{y};{x}H");
+                }
+                cursor = Some((column + 1, row));
+
+                // Picks the character that needs the fewest color changes
+                let (upper, lower) = cell;
+                let (glyph, set_fg, set_bg) = if upper == lower {
+                    if bg == Some(upper) {
+                        (" ", None, None)
+                    } else if fg == Some(upper) {
+                        (FULL_BLOCK, None, None)
+                    } else {
+                        (" ", None, Some(upper))
+                    }
+                } else if fg == Some(lower) && bg == Some(upper) {
+                    (LOWER_HALF, None, None)
+                } else {
+                    let set_fg = (fg != Some(upper)).then_some(upper);
+                    let set_bg = (bg != Some(lower)).then_some(lower);
+                    (UPPER_HALF, set_fg, set_bg)
+                };
+                if set_fg.is_some() || set_bg.is_some() {
+                    self.out.push_str("\x1b[");
+                    if let Some(color) = set_fg {
+                        self.push_color(38, color);
+                        fg = set_fg;
+                    }
+                    if let Some(color) = set_bg {
+                        self.push_color(48, color);
+                        bg = set_bg;
+                    }
+                    self.out.push('m');
+                }
+                self.out.push_str(glyph);
+            }
+        }
+        if changed > 0 {
+            self.out.push_str("\x1b[0m");
+            sink.write_bytes(self.out.as_bytes())?;
+        }
+        Ok(changed)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{Bitmap, Font, FontError, Glyph};
+
+/// Returns an iterator over the lines of `text` and the byte offset at which each of them starts
+fn lines_with_offsets(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line.trim_end()))
+    })
+}
+
+/// Parses the whitespace separated integers after a keyword
+fn numbers<const N: usize>(args: &str) -> Option<[i32; N]> {
+    let mut values = [0; N];
+    let mut args = args.split_ascii_whitespace();
+    for value in &mut values {
+        *value = args.next()?.parse().ok()?;
+    }
+    Some(values)
+}
+
+/// Font in the X11 Glyph Bitmap Distribution Format (BDF).
+///
+/// BDF is a text format, the font borrows it and decodes the glyph it is asked for on every lookup, so it works
+/// without allocating but each lookup scans the glyph definitions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BdfFont<'a> {
+    /// The glyph definitions, starting at the first `STARTCHAR`
+    chars: &'a str,
+    ascent: i32,
+    descent: i32,
+    default_char: Option<u32>,
+}
+
+impl<'a> BdfFont<'a> {
+    /// Parses the header of a BDF font
+    pub fn parse(data: &'a [u8]) -> Result<Self, FontError> {
+        let text = core::str::from_utf8(data).map_err(|_| FontError::InvalidHeader)?;
+        if !text.trim_start().starts_with("STARTFONT") {
+            return Err(FontError::InvalidMagic);
+        }
+        let (mut bounding_box, mut ascent, mut descent, mut default_char) =
+            (None, None, None, None);
+        let mut chars = None;
+        for (offset, line) in lines_with_offsets(text) {
+            let (keyword, args) = line.split_once(' ').unwrap_or((line, ""));
+            match keyword {
+                "FONTBOUNDINGBOX" => bounding_box = numbers::<4>(args),
+                "FONT_ASCENT" => ascent = numbers::<1>(args).map(|[a]| a),
+                "FONT_DESCENT" => descent = numbers::<1>(args).map(|[d]| d),
+                "DEFAULT_CHAR" => default_char = numbers::<1>(args).map(|[c]| c as u32),
+                "STARTCHAR" => {
+                    chars = Some(&text[offset..]);
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let [_, h, _, y] = bounding_box.ok_or(FontError::InvalidHeader)?;
+        Ok(Self {
+            chars: chars.unwrap_or(""),
+            ascent: ascent.unwrap_or(h + y),
+            descent: descent.unwrap_or(-y),
+            default_char,
+        })
+    }
+
+    /// Returns the distance from the top of a line to the baseline
+    pub fn ascent(&self) -> i32 {
+        self.ascent
+    }
+
+    /// Returns the distance from the baseline to the bottom of a line
+    pub fn descent(&self) -> i32 {
+        self.descent
+    }
+
+    /// Returns an iterator over the encoding and glyph of every character in the font.
+    /// Characters without an encoding (`ENCODING -1`) are skipped.
+    pub fn glyphs(&self) -> impl Iterator<Item = (u32, Glyph<'a>)> + '_ {
+        let chars = self.chars;
+        let mut lines = lines_with_offsets(chars);
+        core::iter::from_fn(move || loop {
+            let mut encoding = None;
+            let (mut advance, mut bbx) = (None, None);
+            let mut bitmap_start = None;
+            let mut ended = false;
+            for (offset, line) in lines.by_ref() {
+                let (keyword, args) = line.split_once(' ').unwrap_or((line, ""));
+                match keyword {
+                    "ENCODING" => encoding = numbers::<1>(args).map(|[e]| e),
+                    "DWIDTH" => advance = numbers::<1>(args).map(|[dx]| dx),
+                    "BBX" => bbx = numbers::<4>(args),
+                    "BITMAP" => bitmap_start = Some(offset + line.len()),
+                    "ENDCHAR" => {
+                        let rows = bitmap_start.map_or("", |start| &chars[start..offset]);
+                        let [w, h, x, y] = bbx.unwrap_or_default();
+                        let glyph = Glyph {
+                            bitmap: Bitmap::Hex(rows.trim_start()),
+                            width: w.max(0) as usize,
+                            height: h.max(0) as usize,
+                            offset: (x, self.ascent - (h + y)),
+                            advance: advance.unwrap_or(w + x).max(0) as usize,
+                        };
+                        ended = true;
+                        match encoding {
+                            Some(e) if e >= 0 => return Some((e as u32, glyph)),
+                            _ => break,
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            if !ended {
+                return None;
+            }
+        })
+    }
+
+    /// Returns the glyph with the given encoding, which is the Unicode codepoint for ISO10646 fonts
+    pub fn glyph_by_encoding(&self, encoding: u32) -> Option<Glyph<'a>> {
+        self.glyphs()
+            .find(|(e, _)| *e == encoding)
+            .map(|(_, glyph)| glyph)
+    }
+}
+
+impl Font for BdfFont<'_> {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        self.glyph_by_encoding(c as u32)
+    }
+
+    fn line_height(&self) -> usize {
+        (self.ascent + self.descent).max(0) as usize
+    }
+
+    /// Prefers the font's `DEFAULT_CHAR` over the generic fallbacks
+    fn glyph_or_fallback(&self, c: char) -> Option<Glyph<'_>> {
+        self.glyph(c)
+            .or_else(|| self.glyph_by_encoding(self.default_char?))
+            .or_else(|| self.glyph(char::REPLACEMENT_CHARACTER))
+            .or_else(|| self.glyph('?'))
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{math, Canvas, Draw};
+
+/// Maximum value of a linear-light channel as returned by `srgb_to_linear`.
+/// Linear values use 12 bits of precision, which is enough to round-trip every 8-bit sRGB value.
+pub const LINEAR_MAX: u16 = 4095;
+
+static SRGB_TO_LINEAR: [u16; 256] = srgb_to_linear_table();
+static LINEAR_TO_SRGB: [u8; LINEAR_MAX as usize + 1] = linear_to_srgb_table();
+
+pub(crate) const fn decode(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        math::powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+pub(crate) const fn encode(l: f64) -> f64 {
+    if l <= 0.0031308 {
+        l * 12.92
+    } else {
+        1.055 * math::powf(l, 1. / 2.4) - 0.055
+    }
+}
+
+const fn srgb_to_linear_table() -> [u16; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = math::round(decode(i as f64 / 255.) * LINEAR_MAX as f64) as u16;
+        i += 1;
+    }
+    table
+}
+
+const fn linear_to_srgb_table() -> [u8; LINEAR_MAX as usize + 1] {
+    let mut table = [0; LINEAR_MAX as usize + 1];
+    let mut i = 0;
+    while i <= LINEAR_MAX as usize {
+        table[i] = math::round(encode(i as f64 / LINEAR_MAX as f64) * 255.) as u8;
+        i += 1;
+    }
+    table
+}
+
+/// Converts an sRGB-encoded channel into linear light in the `0..=LINEAR_MAX` range.
+pub fn srgb_to_linear(c: u8) -> u16 {
+    SRGB_TO_LINEAR[c as usize]
+}
+
+/// Converts a linear-light channel in the `0..=LINEAR_MAX` range back into an sRGB-encoded channel.
+/// Values above `LINEAR_MAX` are clamped.
+pub fn linear_to_srgb(l: u16) -> u8 {
+    LINEAR_TO_SRGB[l.min(LINEAR_MAX) as usize]
+}
+
+/// The space in which color channels are mixed by compositing brushes and gradients.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlendSpace {
+    /// Mixes the sRGB-encoded channel values directly. This is cheap but produces dark fringes
+    /// and muddy gradients.
+    #[default]
+    Srgb,
+    /// Converts the channels into linear light, mixes them and converts the result back to sRGB.
+    Linear,
+}
+
+impl BlendSpace {
+    /// Mixes two sRGB-encoded channels, `t = 0` returns `a` and `t = 255` returns `b`.
+    pub fn mix(self, a: u8, b: u8, t: u8) -> u8 {
+        let t = t as u32;
+        match self {
+            Self::Srgb => ((a as u32 * (255 - t) + b as u32 * t + 127) / 255) as u8,
+            Self::Linear => {
+                let a = srgb_to_linear(a) as u32;
+                let b = srgb_to_linear(b) as u32;
+                linear_to_srgb(((a * (255 - t) + b * t + 127) / 255) as u16)
+            }
+        }
+    }
+
+    /// Mixes two pixels in the 00000000RRRRRRRRGGGGGGGGBBBBBBBB format channel by channel.
+    /// The upper 8 bits of the result are taken from `a`.
+    pub fn mix_u32(self, a: u32, b: u32, t: u8) -> u32 {
+        let channel =
+            |shift: u32| (self.mix((a >> shift) as u8, (b >> shift) as u8, t) as u32) << shift;
+        (a & 0xff000000) | channel(16) | channel(8) | channel(0)
+    }
+}
+
+/// Composites `src` over `dst` with the given `alpha`, both pixels are in the 00000000RRRRRRRRGGGGGGGGBBBBBBBB format.
+/// The destination is treated as opaque and its upper 8 bits are preserved.
+pub fn blend(dst: u32, src: u32, alpha: u8, space: BlendSpace) -> u32 {
+    space.mix_u32(dst, src, alpha)
+}
+
+/// Compositing brush that blends a color with straight alpha over the pixels already on a `u32` canvas.
+///
+/// The color channels are sRGB-encoded, `space` decides whether the blending happens on the encoded
+/// values or in linear light.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+    pub space: BlendSpace,
+}
+
+impl Rgba {
+    /// Creates a new `Rgba` that blends in `BlendSpace::Srgb`
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            a,
+            space: BlendSpace::Srgb,
+        }
+    }
+
+    /// Returns a copy of `self` that blends in `BlendSpace::Linear`
+    pub const fn linear(self) -> Self {
+        Self {
+            space: BlendSpace::Linear,
+            ..self
+        }
+    }
+
+    /// Returns the color channels in the 00000000RRRRRRRRGGGGGGGGBBBBBBBB format
+    pub const fn rgb_u32(&self) -> u32 {
+        ((self.r as u32) << 16) | ((self.g as u32) << 8) | (self.b as u32)
+    }
+}
+
+impl Draw for Rgba {
+    type T = u32;
+    fn draw(&self, canvas: &mut Canvas<u32, &mut [u32]>, x: i32, y: i32) {
+        let src = self.rgb_u32();
+        canvas.put_with(x, y, |dst| blend(*dst, src, self.a, self.space));
+    }
+}
@@ -0,0 +1,201 @@
+use core::{
+    marker::PhantomData,
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+use crate::{Canvas, Draw};
+
+/// Pixel types that can be combined with the bitwise `RasterOp`s, implemented for every type that has the bitwise
+/// operators, like the `u32` used by `RGBu32`.
+pub trait Bitwise:
+    Copy + PartialEq + BitAnd<Output = Self> + BitOr<Output = Self> + BitXor<Output = Self>
+{
+}
+
+impl<T: Copy + PartialEq + BitAnd<Output = T> + BitOr<Output = T> + BitXor<Output = T>> Bitwise
+    for T
+{
+}
+
+/// How a blitted source pixel is combined with the destination pixel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RasterOp<T> {
+    /// Replaces the destination
+    #[default]
+    Copy,
+    /// `destination & source`
+    And,
+    /// `destination | source`
+    Or,
+    /// `destination ^ source`, blitting the same region twice restores the destination
+    Xor,
+    /// Replaces the destination, except for source pixels that are equal to the color key
+    ColorKey(T),
+}
+
+impl<T: Bitwise> RasterOp<T> {
+    /// Combines `src` with `dst`, returns `None` if the destination should be left untouched
+    pub fn apply(self, dst: T, src: T) -> Option<T> {
+        match self {
+            Self::Copy => Some(src),
+            Self::And => Some(dst & src),
+            Self::Or => Some(dst | src),
+            Self::Xor => Some(dst ^ src),
+            Self::ColorKey(key) => (src != key).then_some(src),
+        }
+    }
+}
+
+/// Brush that XORs its value into every surface pixel it touches instead of replacing it.
+///
+/// XOR is its own inverse, so drawing the same shapes with the same `Xor` brush twice restores the original buffer
+/// exactly. This makes it useful for rubber-band selections and cursors that are erased by drawing them again.
+/// For pixels in the 00000000RRRRRRRRGGGGGGGGBBBBBBBB format, `Xor(0xffffff)` inverts the color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Xor<T>(pub T);
+
+impl<T: Bitwise> Draw for Xor<T> {
+    type T = T;
+    fn draw(&self, canvas: &mut Canvas<T, &mut [T]>, x: i32, y: i32) {
+        canvas.put_with(x, y, |dst| *dst ^ self.0);
+    }
+}
+
+/// Brush that replaces every surface pixel it touches with its bitwise complement (`!pixel`).
+/// Like `Xor`, drawing the same shapes twice restores the original buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Invert<T>(PhantomData<T>);
+
+impl<T> Invert<T> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Clone + Not<Output = T>> Draw for Invert<T> {
+    type T = T;
+    fn draw(&self, canvas: &mut Canvas<T, &mut [T]>, x: i32, y: i32) {
+        canvas.put_with(x, y, |dst| !dst.clone());
+    }
+}
+
+/// Options of `Canvas::blit_with`.
+///
+/// Scaling and flipping aren't available within one canvas, where the destination could overwrite source pixels that
+/// are still needed. `Canvas::copy_within_with` only takes the `RasterOp`, blit from a copy of the canvas otherwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlitOptions<T> {
+    /// Size of the destination rectangle in canvas pixels, the source rectangle is scaled to it with
+    /// nearest-neighbour sampling. `None` keeps the size of the source rectangle.
+    pub size: Option<(usize, usize)>,
+    pub flip_h: bool,
+    pub flip_v: bool,
+    pub op: RasterOp<T>,
+}
+
+/// Calls `f` with every (destination offset, source offset) pair of a blit along one axis
+fn span<F: FnMut(i32, i32)>(src_len: usize, dst_len: usize, flip: bool, mut f: F) {
+    for d in 0..dst_len {
+        let s = d * src_len / dst_len;
+        let s = if flip { src_len - 1 - s } else { s };
+        f(d as i32, s as i32);
+    }
+}
+
+/// Calls `f` with every (column, row) offset of a copy of `src_rect` to (`dst_x`, `dst_y`) on the same canvas, in an
+/// order that never reads a pixel that was already overwritten
+fn copy_order<F: FnMut(i32, i32)>(
+    src_rect: (i32, i32, usize, usize),
+    dst_x: i32,
+    dst_y: i32,
+    mut f: F,
+) {
+    let (x, y, w, h) = src_rect;
+    let (w, h) = (w as i32, h as i32);
+    // Copy away from the direction of the move
+    let rows_reversed = dst_y > y;
+    let cols_reversed = dst_x > x;
+    for row in 0..h {
+        let row = if rows_reversed { h - 1 - row } else { row };
+        for col in 0..w {
+            let col = if cols_reversed { w - 1 - col } else { col };
+            f(col, row);
+        }
+    }
+}
+
+impl<T: Clone, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
+    /// Copies the `(x, y, w, h)` rectangle `src_rect` of `src` to (`dst_x`, `dst_y`).
+    ///
+    /// Both positions are in canvas coordinates, so pixels are read with `Canvas::get` and written with `Canvas::put`
+    /// and the two canvases may have different scaling.
+    pub fn blit<S: AsMut<[T]> + AsRef<[T]>>(
+        &mut self,
+        src: &Canvas<T, S>,
+        src_rect: (i32, i32, usize, usize),
+        dst_x: i32,
+        dst_y: i32,
+    ) {
+        let (x, y, w, h) = src_rect;
+        for row in 0..h as i32 {
+            for col in 0..w as i32 {
+                self.put(dst_x + col, dst_y + row, src.get(x + col, y + row).clone());
+            }
+        }
+    }
+
+    /// Copies the `(x, y, w, h)` rectangle `src_rect` of this canvas to (`dst_x`, `dst_y`) on the same canvas.
+    /// The rectangles may overlap, the pixels are copied in an order that never reads a pixel that was already
+    /// overwritten.
+    pub fn copy_within(&mut self, src_rect: (i32, i32, usize, usize), dst_x: i32, dst_y: i32) {
+        let (x, y, _, _) = src_rect;
+        copy_order(src_rect, dst_x, dst_y, |col, row| {
+            let val = self.get(x + col, y + row).clone();
+            self.put(dst_x + col, dst_y + row, val);
+        });
+    }
+}
+
+impl<T: Bitwise, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
+    /// Like `blit`, but can scale and flip the source rectangle and combine it with the destination using a `RasterOp`.
+    pub fn blit_with<S: AsMut<[T]> + AsRef<[T]>>(
+        &mut self,
+        src: &Canvas<T, S>,
+        src_rect: (i32, i32, usize, usize),
+        dst_x: i32,
+        dst_y: i32,
+        options: BlitOptions<T>,
+    ) {
+        let (x, y, w, h) = src_rect;
+        let (dst_w, dst_h) = options.size.unwrap_or((w, h));
+        if w == 0 || h == 0 {
+            return;
+        }
+        span(h, dst_h, options.flip_v, |dy, sy| {
+            span(w, dst_w, options.flip_h, |dx, sx| {
+                let val = *src.get(x + sx, y + sy);
+                self.put_with(dst_x + dx, dst_y + dy, |dst| {
+                    options.op.apply(*dst, val).unwrap_or(*dst)
+                });
+            });
+        });
+    }
+
+    /// Like `copy_within`, but combines the copied pixels with the destination using a `RasterOp`.
+    /// The rectangles may overlap, every destination pixel is combined with the source pixel as it was before the copy.
+    pub fn copy_within_with(
+        &mut self,
+        src_rect: (i32, i32, usize, usize),
+        dst_x: i32,
+        dst_y: i32,
+        op: RasterOp<T>,
+    ) {
+        let (x, y, _, _) = src_rect;
+        copy_order(src_rect, dst_x, dst_y, |col, row| {
+            let val = *self.get(x + col, y + row);
+            self.put_with(dst_x + col, dst_y + row, |dst| {
+                op.apply(*dst, val).unwrap_or(*dst)
+            });
+        });
+    }
+}
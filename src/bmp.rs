@@ -0,0 +1,344 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{sink::Batch, Color, Image, PixelFormat, Sink};
+
+/// Error returned when an encoded image can't be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The data doesn't start with the signature of the format
+    InvalidMagic,
+    /// The data ends before the image is complete
+    Truncated,
+    /// A header field has an invalid value
+    InvalidHeader,
+    /// The image uses a feature of the format that isn't supported
+    Unsupported,
+    /// The compressed data is invalid or doesn't match its checksum
+    Corrupt,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidMagic => "unknown image format",
+            Self::Truncated => "image data is truncated",
+            Self::InvalidHeader => "invalid image header",
+            Self::Unsupported => "unsupported image feature",
+            Self::Corrupt => "image data is corrupt",
+        })
+    }
+}
+
+const FILE_HEADER_LEN: usize = 14;
+const CORE_HEADER_LEN: usize = 12;
+const INFO_HEADER_LEN: usize = 40;
+const V4_HEADER_LEN: usize = 108;
+const BI_RGB: u32 = 0;
+const BI_BITFIELDS: u32 = 3;
+/// `LCS_sRGB` color space of the V4 header
+const LCS_SRGB: u32 = 0x7352_4742;
+
+fn u16_at(data: &[u8], offset: usize) -> Result<u16, DecodeError> {
+    let bytes = data.get(offset..offset + 2).ok_or(DecodeError::Truncated)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Result<u32, DecodeError> {
+    let bytes = data.get(offset..offset + 4).ok_or(DecodeError::Truncated)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Returns the number of bytes per row of pixels, rows are padded to a multiple of 4 bytes
+const fn row_stride(width: usize, bits: usize) -> usize {
+    (width * bits).div_ceil(32) * 4
+}
+
+/// Extracts the channel selected by `mask` and scales it to 8 bits
+fn channel(pixel: u32, mask: u32) -> u8 {
+    if mask == 0 {
+        return 0;
+    }
+    let max = mask >> mask.trailing_zeros();
+    let value = (pixel & mask) >> mask.trailing_zeros();
+    ((value as u64 * 255 + max as u64 / 2) / max as u64) as u8
+}
+
+/// A BMP image that is decoded while it is read, so it can be used without allocating.
+///
+/// Supported are uncompressed images with 1, 4 or 8 bits per pixel and a palette, and with 16, 24 or 32 bits per
+/// pixel, including 16 and 32 bit images with bit field masks. Run-length encoded images are not supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bmp<'a> {
+    pixels: &'a [u8],
+    palette: &'a [u8],
+    palette_entry_len: usize,
+    width: usize,
+    height: usize,
+    bits: usize,
+    top_down: bool,
+    /// Red, green, blue and alpha masks of 16 and 32 bit images
+    masks: [u32; 4],
+}
+
+impl<'a> Bmp<'a> {
+    /// Parses the headers of a BMP file
+    pub fn parse(data: &'a [u8]) -> Result<Self, DecodeError> {
+        if !data.starts_with(b"BM") {
+            return Err(DecodeError::InvalidMagic);
+        }
+        let pixel_offset = u32_at(data, 10)? as usize;
+        let header_len = u32_at(data, FILE_HEADER_LEN)? as usize;
+        let header = FILE_HEADER_LEN;
+        let (width, height, bits, compression, colors_used) = if header_len == CORE_HEADER_LEN {
+            let width = u16_at(data, header + 4)? as i32;
+            let height = u16_at(data, header + 6)? as i16 as i32;
+            (width, height, u16_at(data, header + 10)?, BI_RGB, 0)
+        } else if header_len >= INFO_HEADER_LEN {
+            (
+                u32_at(data, header + 4)? as i32,
+                u32_at(data, header + 8)? as i32,
+                u16_at(data, header + 14)?,
+                u32_at(data, header + 16)?,
+                u32_at(data, header + 32)? as usize,
+            )
+        } else {
+            return Err(DecodeError::InvalidHeader);
+        };
+        if width < 0 || height == i32::MIN {
+            return Err(DecodeError::InvalidHeader);
+        }
+        let (width, bits) = (width as usize, bits as usize);
+        let (top_down, height) = (height < 0, height.unsigned_abs() as usize);
+
+        // Masks follow a 40 byte header, larger headers contain them at the same position
+        let masks_len = match compression {
+            BI_BITFIELDS if header_len == INFO_HEADER_LEN => 12,
+            _ => 0,
+        };
+        let masks = match (compression, bits) {
+            (BI_RGB, 16) => [0x7c00, 0x03e0, 0x001f, 0],
+            (BI_RGB, 24 | 32) => [0xff0000, 0x00ff00, 0x0000ff, 0],
+            (BI_BITFIELDS, 16 | 32) => {
+                let masks = header + INFO_HEADER_LEN;
+                let alpha = if header_len >= 56 {
+                    u32_at(data, masks + 12)?
+                } else {
+                    0
+                };
+                [
+                    u32_at(data, masks)?,
+                    u32_at(data, masks + 4)?,
+                    u32_at(data, masks + 8)?,
+                    alpha,
+                ]
+            }
+            (BI_RGB, 1 | 4 | 8) => [0; 4],
+            _ => return Err(DecodeError::Unsupported),
+        };
+
+        let palette_entry_len = if header_len == CORE_HEADER_LEN { 3 } else { 4 };
+        let palette = if bits <= 8 {
+            let colors = if colors_used == 0 {
+                1 << bits
+            } else {
+                colors_used.min(1 << bits)
+            };
+            let start = header + header_len + masks_len;
+            data.get(start..start + colors * palette_entry_len)
+                .ok_or(DecodeError::Truncated)?
+        } else {
+            &[]
+        };
+
+        let len = row_stride(width, bits)
+            .checked_mul(height)
+            .ok_or(DecodeError::InvalidHeader)?;
+        let pixels = data
+            .get(pixel_offset..)
+            .and_then(|pixels| pixels.get(..len))
+            .ok_or(DecodeError::Truncated)?;
+        Ok(Self {
+            pixels,
+            palette,
+            palette_entry_len,
+            width,
+            height,
+            bits,
+            top_down,
+            masks,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `(width, height)`
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn bits_per_pixel(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns `true` if the rows are stored from the top down instead of the usual bottom up
+    pub fn is_top_down(&self) -> bool {
+        self.top_down
+    }
+
+    /// Returns `true` if the pixels have an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.masks[3] != 0
+    }
+
+    /// Returns the color of the pixel at (`x`, `y`), with `y` counted from the top.
+    ///
+    /// Panics if the position is outside of the image.
+    pub fn pixel(&self, x: usize, y: usize) -> Color {
+        assert!(
+            x < self.width && y < self.height,
+            "pixel outside of the image"
+        );
+        let row = if self.top_down {
+            y
+        } else {
+            self.height - 1 - y
+        };
+        let row = &self.pixels[row * row_stride(self.width, self.bits)..];
+        match self.bits {
+            1 | 4 | 8 => {
+                let bit = x * self.bits;
+                let shift = 8 - self.bits - bit % 8;
+                let index = (row[bit / 8] >> shift) as usize & ((1 << self.bits) - 1);
+                let entry = index * self.palette_entry_len;
+                match self.palette.get(entry..entry + 3) {
+                    Some(&[b, g, r]) => Color::rgb(r, g, b),
+                    _ => Color::BLACK,
+                }
+            }
+            bits => {
+                let bytes = &row[x * bits / 8..(x + 1) * bits / 8];
+                let pixel = bytes
+                    .iter()
+                    .rev()
+                    .fold(0u32, |pixel, &b| pixel << 8 | b as u32);
+                let [r, g, b, a] = self.masks;
+                let alpha = if a == 0 { 255 } else { channel(pixel, a) };
+                Color::rgba(
+                    channel(pixel, r),
+                    channel(pixel, g),
+                    channel(pixel, b),
+                    alpha,
+                )
+            }
+        }
+    }
+
+    /// Decodes the image into `dst`, a tightly packed buffer of `width * height` pixels like the buffer of a
+    /// `Canvas` with the same size.
+    ///
+    /// Panics if `dst` is too short.
+    pub fn decode_into<P: PixelFormat>(&self, dst: &mut [P]) {
+        assert!(
+            dst.len() >= self.width * self.height,
+            "destination is too short"
+        );
+        for y in 0..self.height {
+            for x in 0..self.width {
+                dst[x + y * self.width] = P::from_color(self.pixel(x, y));
+            }
+        }
+    }
+
+    /// Decodes the image into a new `Image`
+    #[cfg(feature = "alloc")]
+    pub fn to_image<P: PixelFormat>(&self) -> Image<P, Vec<P>> {
+        let mut data = Vec::with_capacity(self.width * self.height);
+        for y in 0..self.height {
+            data.extend((0..self.width).map(|x| P::from_color(self.pixel(x, y))));
+        }
+        Image::new(data, self.width, self.height)
+    }
+}
+
+/// Options of `write_bmp`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BmpOptions {
+    /// Writes 32 bits per pixel with an alpha channel instead of 24 bits per pixel
+    pub alpha: bool,
+    /// Stores the rows from the top down, the default is the more widely supported bottom up order
+    pub top_down: bool,
+}
+
+/// Writes `image` as an uncompressed BMP file.
+///
+/// 24-bit images use the common `BITMAPINFOHEADER`, 32-bit images use a `BITMAPV4HEADER` with bit field masks so
+/// that readers pick up the alpha channel.
+pub fn write_bmp<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<P, B>,
+    options: BmpOptions,
+) -> Result<(), S::Error> {
+    let (w, h) = image.size();
+    let bits = if options.alpha { 32 } else { 24 };
+    let header_len = if options.alpha {
+        V4_HEADER_LEN
+    } else {
+        INFO_HEADER_LEN
+    };
+    let stride = row_stride(w, bits);
+    let pixel_offset = FILE_HEADER_LEN + header_len;
+    let height = if options.top_down {
+        -(h as i32)
+    } else {
+        h as i32
+    };
+
+    let mut out = Batch::new(sink);
+    out.extend(b"BM")?;
+    out.extend(&((pixel_offset + stride * h) as u32).to_le_bytes())?;
+    out.extend(&[0; 4])?;
+    out.extend(&(pixel_offset as u32).to_le_bytes())?;
+
+    out.extend(&(header_len as u32).to_le_bytes())?;
+    out.extend(&(w as i32).to_le_bytes())?;
+    out.extend(&height.to_le_bytes())?;
+    out.extend(&1u16.to_le_bytes())?;
+    out.extend(&(bits as u16).to_le_bytes())?;
+    let compression = if options.alpha { BI_BITFIELDS } else { BI_RGB };
+    out.extend(&compression.to_le_bytes())?;
+    out.extend(&((stride * h) as u32).to_le_bytes())?;
+    // 2835 pixels per meter are 72 DPI, followed by the palette size and important colors
+    for field in [2835u32, 2835, 0, 0] {
+        out.extend(&field.to_le_bytes())?;
+    }
+    if options.alpha {
+        for mask in [0x00ff0000u32, 0x0000ff00, 0x000000ff, 0xff000000, LCS_SRGB] {
+            out.extend(&mask.to_le_bytes())?;
+        }
+        // Unused endpoints and gamma of calibrated color spaces
+        out.extend(&[0; V4_HEADER_LEN - 60])?;
+    }
+
+    let padding = stride - w * bits / 8;
+    for row in 0..h {
+        let y = if options.top_down { row } else { h - 1 - row };
+        for x in 0..w {
+            let c = image.get(x, y).map_or(Color::BLACK, |p| p.to_color());
+            out.extend(&[c.b, c.g, c.r])?;
+            if options.alpha {
+                out.push(c.a)?;
+            }
+        }
+        out.extend(&[0; 3][..padding])?;
+    }
+    out.flush()
+}
@@ -0,0 +1,119 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::{ansi::xterm_index, AnsiColors, Color, Image, PixelFormat};
+
+/// Bit of the dot at (x, y) of a cell in the Braille patterns, the dots are numbered down the left column first
+const DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Renders images as text with the Braille patterns (U+2800 to U+28FF), which gives every character cell 2x4 dots.
+///
+/// A dot is set where the luma of a pixel is at least the threshold, so monochrome canvases and plots drawn in any
+/// pixel format work. The output has one line per 4 rows of pixels and can be printed over SSH or into logs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BrailleRenderer {
+    threshold: u8,
+    invert: bool,
+    colors: Option<AnsiColors>,
+}
+
+impl Default for BrailleRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BrailleRenderer {
+    /// Creates a renderer that sets the dots of pixels with a luma of at least 128, without colors
+    pub const fn new() -> Self {
+        Self {
+            threshold: 128,
+            invert: false,
+            colors: None,
+        }
+    }
+
+    /// Sets the luma from which on a pixel sets its dot, 128 by default
+    pub fn with_threshold(self, threshold: u8) -> Self {
+        Self { threshold, ..self }
+    }
+
+    /// Sets the dots of the pixels below the threshold instead, for dark drawings on a light background
+    pub fn with_invert(self, invert: bool) -> Self {
+        Self { invert, ..self }
+    }
+
+    /// Colors every cell with the average color of its set dots, using ANSI escape sequences
+    pub fn with_colors(self, colors: Option<AnsiColors>) -> Self {
+        Self { colors, ..self }
+    }
+
+    fn is_set(&self, c: Color) -> bool {
+        (c.luma() >= self.threshold) != self.invert
+    }
+
+    /// Writes `image` as lines of Braille patterns to `out`, every line ends with a newline.
+    ///
+    /// A `Canvas` can be written with `renderer.write(&mut out, &canvas.surface())`.
+    pub fn write<P: PixelFormat, B: AsRef<[P]>, W: fmt::Write>(
+        &self,
+        out: &mut W,
+        image: &Image<P, B>,
+    ) -> fmt::Result {
+        let (w, h) = image.size();
+        for row in 0..h.div_ceil(4) {
+            let mut current = None;
+            for column in 0..w.div_ceil(2) {
+                let mut pattern = 0;
+                let mut sum = [0u32; 3];
+                for (dy, bits) in DOTS.iter().enumerate() {
+                    for (dx, bit) in bits.iter().enumerate() {
+                        let Some(p) = image.get(column * 2 + dx, row * 4 + dy) else {
+                            continue;
+                        };
+                        let c = p.to_color();
+                        if self.is_set(c) {
+                            pattern |= bit;
+                            sum[0] += c.r as u32;
+                            sum[1] += c.g as u32;
+                            sum[2] += c.b as u32;
+                        }
+                    }
+                }
+
+                // Empty cells show no dots, so they keep the color of the previous cell
+                if let (Some(colors), 1..) = (self.colors, pattern) {
+                    let n = pattern.count_ones();
+                    let [r, g, b] = sum.map(|s| ((s + n / 2) / n) as u8);
+                    let color = match colors {
+                        AnsiColors::TrueColor => Color::rgb(r, g, b).to_u32(),
+                        AnsiColors::Indexed => xterm_index(Color::rgb(r, g, b)) as u32,
+                    };
+                    if current != Some(color) {
+                        match colors {
+                            AnsiColors::TrueColor => write!(out, "\x1b[38;2;{r};{g};{b}m")?,
+                            AnsiColors::Indexed => write!(out, "\x1b[38;5;{color}m")?,
+                        }
+                        current = Some(color);
+                    }
+                }
+                out.write_char(char::from_u32(0x2800 + pattern as u32).unwrap())?;
+            }
+            if current.is_some() {
+                out.write_str("\x1b[0m")?;
+            }
+            out.write_char('\n')?;
+        }
+        Ok(())
+    }
+
+    /// Renders `image` into a new `String`, like `write`
+    #[cfg(feature = "alloc")]
+    pub fn render<P: PixelFormat, B: AsRef<[P]>>(&self, image: &Image<P, B>) -> String {
+        let mut out = String::new();
+        let _ = self.write(&mut out, image);
+        out
+    }
+}
@@ -0,0 +1,523 @@
+use core::{fmt, str::FromStr};
+
+use crate::{blend, math, Canvas, Draw, PixelFormat, RGBu32, Rgba};
+
+/// A color with 8-bit sRGB-encoded channels and straight (non-premultiplied) alpha.
+///
+/// `Color` can be constructed from RGB, HSV, HSL and Oklab values, parsed from hex strings and CSS color names,
+/// and converted into any `PixelFormat` with `Color::to_pixel`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+/// The space in which `Color::lerp` interpolates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// sRGB-encoded channels
+    #[default]
+    Srgb,
+    /// Linear-light RGB
+    Linear,
+    /// Hue, saturation and value, the hue takes the shortest path around the color wheel
+    Hsv,
+    /// Hue, saturation and lightness, the hue takes the shortest path around the color wheel
+    Hsl,
+    /// The perceptually uniform Oklab space
+    Oklab,
+}
+
+/// Error returned when parsing a `Color` from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// A hex color didn't have 3, 4, 6 or 8 digits
+    InvalidLength,
+    /// A hex color contained a character that isn't a hex digit
+    InvalidDigit,
+    /// The string isn't a hex color nor a CSS color name
+    UnknownName,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidLength => "hex color must have 3, 4, 6 or 8 digits",
+            Self::InvalidDigit => "invalid digit in hex color",
+            Self::UnknownName => "unknown color name",
+        })
+    }
+}
+
+fn to_u8(c: f32) -> u8 {
+    math::round(c.clamp(0., 1.) as f64 * 255.) as u8
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Interpolates between two hues in degrees along the shortest arc
+fn lerp_hue(a: f32, b: f32, t: f32) -> f32 {
+    let mut d = b - a;
+    if d > 180. {
+        d -= 360.;
+    } else if d < -180. {
+        d += 360.;
+    }
+    a + d * t
+}
+
+/// Wraps a hue in degrees into the `0..360` range
+fn wrap_hue(h: f32) -> f32 {
+    let h = h as f64;
+    (h - math::floor(h / 360.) * 360.) as f32
+}
+
+fn hex_digit(c: u8) -> Result<u8, ParseColorError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ParseColorError::InvalidDigit),
+    }
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+
+    /// Creates an opaque color
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    /// Creates a color with the given alpha
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Returns a copy of `self` with its alpha replaced
+    pub const fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    /// Creates an opaque color from a pixel in the 00000000RRRRRRRRGGGGGGGGBBBBBBBB format, the upper 8 bits are ignored
+    pub const fn from_u32(p: u32) -> Self {
+        Self::rgb((p >> 16) as u8, (p >> 8) as u8, p as u8)
+    }
+
+    /// Returns the color in the 00000000RRRRRRRRGGGGGGGGBBBBBBBB format, alpha is dropped
+    pub const fn to_u32(self) -> u32 {
+        ((self.r as u32) << 16) | ((self.g as u32) << 8) | (self.b as u32)
+    }
+
+    /// Converts the color into any `PixelFormat`
+    pub fn to_pixel<P: PixelFormat>(self) -> P {
+        P::from_color(self)
+    }
+
+    /// Creates an opaque color from sRGB-encoded channels in the `0..=1` range
+    pub fn from_rgb_f32(r: f32, g: f32, b: f32) -> Self {
+        Self::rgb(to_u8(r), to_u8(g), to_u8(b))
+    }
+
+    /// Returns the sRGB-encoded channels in the `0..=1` range
+    pub fn to_rgb_f32(self) -> (f32, f32, f32) {
+        (
+            self.r as f32 / 255.,
+            self.g as f32 / 255.,
+            self.b as f32 / 255.,
+        )
+    }
+
+    /// Creates an opaque color from linear-light channels in the `0..=1` range
+    pub fn from_linear(r: f32, g: f32, b: f32) -> Self {
+        let encode = |c: f32| to_u8(blend::encode(c.clamp(0., 1.) as f64) as f32);
+        Self::rgb(encode(r), encode(g), encode(b))
+    }
+
+    /// Returns the linear-light channels in the `0..=1` range
+    pub fn to_linear(self) -> (f32, f32, f32) {
+        let decode = |c: u8| blend::decode(c as f64 / 255.) as f32;
+        (decode(self.r), decode(self.g), decode(self.b))
+    }
+
+    /// Creates an opaque color from a hue in degrees, and saturation and value in the `0..=1` range
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+        let h = wrap_hue(h) / 60.;
+        let (s, v) = (s.clamp(0., 1.), v.clamp(0., 1.));
+        let c = v * s;
+        let x = c * (1. - ((h as f64 - math::floor(h as f64 / 2.) * 2.) as f32 - 1.).abs());
+        let m = v - c;
+        let (r, g, b) = match h as u32 {
+            0 => (c, x, 0.),
+            1 => (x, c, 0.),
+            2 => (0., c, x),
+            3 => (0., x, c),
+            4 => (x, 0., c),
+            _ => (c, 0., x),
+        };
+        Self::from_rgb_f32(r + m, g + m, b + m)
+    }
+
+    /// Returns the hue in degrees, and the saturation and value in the `0..=1` range
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_rgb_f32();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let d = max - min;
+        let s = if max == 0. { 0. } else { d / max };
+        (hue(r, g, b, max, d), s, max)
+    }
+
+    /// Creates an opaque color from a hue in degrees, and saturation and lightness in the `0..=1` range
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let (s, l) = (s.clamp(0., 1.), l.clamp(0., 1.));
+        let v = l + s * l.min(1. - l);
+        let sv = if v == 0. { 0. } else { 2. * (1. - l / v) };
+        Self::from_hsv(h, sv, v)
+    }
+
+    /// Returns the hue in degrees, and the saturation and lightness in the `0..=1` range
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_rgb_f32();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let d = max - min;
+        let l = (max + min) / 2.;
+        let s = if d == 0. {
+            0.
+        } else {
+            d / (1. - (2. * l - 1.).abs())
+        };
+        (hue(r, g, b, max, d), s, l)
+    }
+
+    /// Creates an opaque color from Oklab lightness `l` and the `a` and `b` opponent axes.
+    /// Colors outside of the sRGB gamut are clipped.
+    pub fn from_oklab(l: f32, a: f32, b: f32) -> Self {
+        let (l, a, b) = (l as f64, a as f64, b as f64);
+        let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+        let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+        let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+        Self::from_linear(
+            (4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s) as f32,
+            (-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s) as f32,
+            (-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s) as f32,
+        )
+    }
+
+    /// Returns the Oklab lightness and the `a` and `b` opponent axes
+    pub fn to_oklab(self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_linear();
+        let (r, g, b) = (r as f64, g as f64, b as f64);
+        let l = math::cbrt(0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b);
+        let m = math::cbrt(0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b);
+        let s = math::cbrt(0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b);
+        (
+            (0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s) as f32,
+            (1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s) as f32,
+            (0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s) as f32,
+        )
+    }
+
+    /// Parses a hex color in the `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` form, the leading `#` is optional
+    pub fn from_hex(s: &str) -> Result<Self, ParseColorError> {
+        let s = s.strip_prefix('#').unwrap_or(s).as_bytes();
+        let mut digits = [0; 8];
+        for (d, c) in digits.iter_mut().zip(s) {
+            *d = hex_digit(*c)?;
+        }
+        let [d0, d1, d2, d3, d4, d5, d6, d7] = digits;
+        match s.len() {
+            3 => Ok(Self::rgb(d0 * 17, d1 * 17, d2 * 17)),
+            4 => Ok(Self::rgba(d0 * 17, d1 * 17, d2 * 17, d3 * 17)),
+            6 => Ok(Self::rgb(d0 << 4 | d1, d2 << 4 | d3, d4 << 4 | d5)),
+            8 => Ok(Self::rgba(
+                d0 << 4 | d1,
+                d2 << 4 | d3,
+                d4 << 4 | d5,
+                d6 << 4 | d7,
+            )),
+            _ => Err(ParseColorError::InvalidLength),
+        }
+    }
+
+    /// Looks up a CSS named color, ignoring ASCII case
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .binary_search_by(|(n, _)| n.bytes().cmp(name.bytes().map(|c| c.to_ascii_lowercase())))
+            .ok()
+            .map(|i| NAMED_COLORS[i].1)
+    }
+
+    /// Interpolates between `self` (`t = 0`) and `other` (`t = 1`) in the given color space.
+    /// Alpha is always interpolated linearly.
+    pub fn lerp(self, other: Color, t: f32, space: ColorSpace) -> Self {
+        let t = t.clamp(0., 1.);
+        let c = match space {
+            ColorSpace::Srgb => {
+                let ((r0, g0, b0), (r1, g1, b1)) = (self.to_rgb_f32(), other.to_rgb_f32());
+                Self::from_rgb_f32(lerp(r0, r1, t), lerp(g0, g1, t), lerp(b0, b1, t))
+            }
+            ColorSpace::Linear => {
+                let ((r0, g0, b0), (r1, g1, b1)) = (self.to_linear(), other.to_linear());
+                Self::from_linear(lerp(r0, r1, t), lerp(g0, g1, t), lerp(b0, b1, t))
+            }
+            ColorSpace::Hsv => {
+                let ((h0, s0, v0), (h1, s1, v1)) = hues(self.to_hsv(), other.to_hsv());
+                Self::from_hsv(lerp_hue(h0, h1, t), lerp(s0, s1, t), lerp(v0, v1, t))
+            }
+            ColorSpace::Hsl => {
+                let ((h0, s0, l0), (h1, s1, l1)) = hues(self.to_hsl(), other.to_hsl());
+                Self::from_hsl(lerp_hue(h0, h1, t), lerp(s0, s1, t), lerp(l0, l1, t))
+            }
+            ColorSpace::Oklab => {
+                let ((l0, a0, b0), (l1, a1, b1)) = (self.to_oklab(), other.to_oklab());
+                Self::from_oklab(lerp(l0, l1, t), lerp(a0, a1, t), lerp(b0, b1, t))
+            }
+        };
+        c.with_alpha(to_u8(lerp(self.a as f32 / 255., other.a as f32 / 255., t)))
+    }
+
+    /// Increases the HSL lightness by `amount` (in the `0..=1` range), keeping alpha
+    pub fn lighten(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount).with_alpha(self.a)
+    }
+
+    /// Decreases the HSL lightness by `amount` (in the `0..=1` range), keeping alpha
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Returns the Rec. 601 luma of the sRGB-encoded channels, used when converting into grayscale formats
+    pub const fn luma(self) -> u8 {
+        ((77 * self.r as u32 + 150 * self.g as u32 + 29 * self.b as u32 + 128) >> 8) as u8
+    }
+}
+
+/// Computes the hue in degrees from normalized RGB channels, their maximum and their range
+fn hue(r: f32, g: f32, b: f32, max: f32, d: f32) -> f32 {
+    if d == 0. {
+        0.
+    } else if max == r {
+        wrap_hue(60. * (g - b) / d)
+    } else if max == g {
+        60. * ((b - r) / d + 2.)
+    } else {
+        60. * ((r - g) / d + 4.)
+    }
+}
+
+/// Gives achromatic colors the hue of the other color so that hue interpolation doesn't swing through red
+fn hues(a: (f32, f32, f32), b: (f32, f32, f32)) -> ((f32, f32, f32), (f32, f32, f32)) {
+    match (a.1 == 0., b.1 == 0.) {
+        (true, false) => ((b.0, a.1, a.2), b),
+        (false, true) => (a, (a.0, b.1, b.2)),
+        _ => (a, b),
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses either a CSS color name or a hex color (see `Color::from_hex`), names take precedence over hex colors
+    /// without a leading `#`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(c) = Self::from_name(s) {
+            return Ok(c);
+        }
+        match Self::from_hex(s) {
+            Err(_) if !s.starts_with('#') => Err(ParseColorError::UnknownName),
+            result => result,
+        }
+    }
+}
+
+impl From<RGBu32> for Color {
+    fn from(c: RGBu32) -> Self {
+        match c {
+            RGBu32::Rgb(r, g, b) => Self::rgb(r, g, b),
+            RGBu32::Pixel(p) => Self::from_u32(p),
+        }
+    }
+}
+
+impl From<Color> for RGBu32 {
+    fn from(c: Color) -> Self {
+        RGBu32::Rgb(c.r, c.g, c.b)
+    }
+}
+
+impl From<Color> for Rgba {
+    fn from(c: Color) -> Self {
+        Rgba::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+/// Opaque colors are `put` directly, translucent colors are blended over the canvas in `BlendSpace::Srgb`.
+/// Use `Rgba` to blend in linear light.
+impl Draw for Color {
+    type T = u32;
+    fn draw(&self, canvas: &mut Canvas<u32, &mut [u32]>, x: i32, y: i32) {
+        Rgba::from(*self).draw(canvas, x, y);
+    }
+}
+
+/// The CSS named colors, sorted by name
+pub static NAMED_COLORS: [(&str, Color); 148] = [
+    ("aliceblue", Color::rgb(0xf0, 0xf8, 0xff)),
+    ("antiquewhite", Color::rgb(0xfa, 0xeb, 0xd7)),
+    ("aqua", Color::rgb(0x00, 0xff, 0xff)),
+    ("aquamarine", Color::rgb(0x7f, 0xff, 0xd4)),
+    ("azure", Color::rgb(0xf0, 0xff, 0xff)),
+    ("beige", Color::rgb(0xf5, 0xf5, 0xdc)),
+    ("bisque", Color::rgb(0xff, 0xe4, 0xc4)),
+    ("black", Color::rgb(0x00, 0x00, 0x00)),
+    ("blanchedalmond", Color::rgb(0xff, 0xeb, 0xcd)),
+    ("blue", Color::rgb(0x00, 0x00, 0xff)),
+    ("blueviolet", Color::rgb(0x8a, 0x2b, 0xe2)),
+    ("brown", Color::rgb(0xa5, 0x2a, 0x2a)),
+    ("burlywood", Color::rgb(0xde, 0xb8, 0x87)),
+    ("cadetblue", Color::rgb(0x5f, 0x9e, 0xa0)),
+    ("chartreuse", Color::rgb(0x7f, 0xff, 0x00)),
+    ("chocolate", Color::rgb(0xd2, 0x69, 0x1e)),
+    ("coral", Color::rgb(0xff, 0x7f, 0x50)),
+    ("cornflowerblue", Color::rgb(0x64, 0x95, 0xed)),
+    ("cornsilk", Color::rgb(0xff, 0xf8, 0xdc)),
+    ("crimson", Color::rgb(0xdc, 0x14, 0x3c)),
+    ("cyan", Color::rgb(0x00, 0xff, 0xff)),
+    ("darkblue", Color::rgb(0x00, 0x00, 0x8b)),
+    ("darkcyan", Color::rgb(0x00, 0x8b, 0x8b)),
+    ("darkgoldenrod", Color::rgb(0xb8, 0x86, 0x0b)),
+    ("darkgray", Color::rgb(0xa9, 0xa9, 0xa9)),
+    ("darkgreen", Color::rgb(0x00, 0x64, 0x00)),
+    ("darkgrey", Color::rgb(0xa9, 0xa9, 0xa9)),
+    ("darkkhaki", Color::rgb(0xbd, 0xb7, 0x6b)),
+    ("darkmagenta", Color::rgb(0x8b, 0x00, 0x8b)),
+    ("darkolivegreen", Color::rgb(0x55, 0x6b, 0x2f)),
+    ("darkorange", Color::rgb(0xff, 0x8c, 0x00)),
+    ("darkorchid", Color::rgb(0x99, 0x32, 0xcc)),
+    ("darkred", Color::rgb(0x8b, 0x00, 0x00)),
+    ("darksalmon", Color::rgb(0xe9, 0x96, 0x7a)),
+    ("darkseagreen", Color::rgb(0x8f, 0xbc, 0x8f)),
+    ("darkslateblue", Color::rgb(0x48, 0x3d, 0x8b)),
+    ("darkslategray", Color::rgb(0x2f, 0x4f, 0x4f)),
+    ("darkslategrey", Color::rgb(0x2f, 0x4f, 0x4f)),
+    ("darkturquoise", Color::rgb(0x00, 0xce, 0xd1)),
+    ("darkviolet", Color::rgb(0x94, 0x00, 0xd3)),
+    ("deeppink", Color::rgb(0xff, 0x14, 0x93)),
+    ("deepskyblue", Color::rgb(0x00, 0xbf, 0xff)),
+    ("dimgray", Color::rgb(0x69, 0x69, 0x69)),
+    ("dimgrey", Color::rgb(0x69, 0x69, 0x69)),
+    ("dodgerblue", Color::rgb(0x1e, 0x90, 0xff)),
+    ("firebrick", Color::rgb(0xb2, 0x22, 0x22)),
+    ("floralwhite", Color::rgb(0xff, 0xfa, 0xf0)),
+    ("forestgreen", Color::rgb(0x22, 0x8b, 0x22)),
+    ("fuchsia", Color::rgb(0xff, 0x00, 0xff)),
+    ("gainsboro", Color::rgb(0xdc, 0xdc, 0xdc)),
+    ("ghostwhite", Color::rgb(0xf8, 0xf8, 0xff)),
+    ("gold", Color::rgb(0xff, 0xd7, 0x00)),
+    ("goldenrod", Color::rgb(0xda, 0xa5, 0x20)),
+    ("gray", Color::rgb(0x80, 0x80, 0x80)),
+    ("green", Color::rgb(0x00, 0x80, 0x00)),
+    ("greenyellow", Color::rgb(0xad, 0xff, 0x2f)),
+    ("grey", Color::rgb(0x80, 0x80, 0x80)),
+    ("honeydew", Color::rgb(0xf0, 0xff, 0xf0)),
+    ("hotpink", Color::rgb(0xff, 0x69, 0xb4)),
+    ("indianred", Color::rgb(0xcd, 0x5c, 0x5c)),
+    ("indigo", Color::rgb(0x4b, 0x00, 0x82)),
+    ("ivory", Color::rgb(0xff, 0xff, 0xf0)),
+    ("khaki", Color::rgb(0xf0, 0xe6, 0x8c)),
+    ("lavender", Color::rgb(0xe6, 0xe6, 0xfa)),
+    ("lavenderblush", Color::rgb(0xff, 0xf0, 0xf5)),
+    ("lawngreen", Color::rgb(0x7c, 0xfc, 0x00)),
+    ("lemonchiffon", Color::rgb(0xff, 0xfa, 0xcd)),
+    ("lightblue", Color::rgb(0xad, 0xd8, 0xe6)),
+    ("lightcoral", Color::rgb(0xf0, 0x80, 0x80)),
+    ("lightcyan", Color::rgb(0xe0, 0xff, 0xff)),
+    ("lightgoldenrodyellow", Color::rgb(0xfa, 0xfa, 0xd2)),
+    ("lightgray", Color::rgb(0xd3, 0xd3, 0xd3)),
+    ("lightgreen", Color::rgb(0x90, 0xee, 0x90)),
+    ("lightgrey", Color::rgb(0xd3, 0xd3, 0xd3)),
+    ("lightpink", Color::rgb(0xff, 0xb6, 0xc1)),
+    ("lightsalmon", Color::rgb(0xff, 0xa0, 0x7a)),
+    ("lightseagreen", Color::rgb(0x20, 0xb2, 0xaa)),
+    ("lightskyblue", Color::rgb(0x87, 0xce, 0xfa)),
+    ("lightslategray", Color::rgb(0x77, 0x88, 0x99)),
+    ("lightslategrey", Color::rgb(0x77, 0x88, 0x99)),
+    ("lightsteelblue", Color::rgb(0xb0, 0xc4, 0xde)),
+    ("lightyellow", Color::rgb(0xff, 0xff, 0xe0)),
+    ("lime", Color::rgb(0x00, 0xff, 0x00)),
+    ("limegreen", Color::rgb(0x32, 0xcd, 0x32)),
+    ("linen", Color::rgb(0xfa, 0xf0, 0xe6)),
+    ("magenta", Color::rgb(0xff, 0x00, 0xff)),
+    ("maroon", Color::rgb(0x80, 0x00, 0x00)),
+    ("mediumaquamarine", Color::rgb(0x66, 0xcd, 0xaa)),
+    ("mediumblue", Color::rgb(0x00, 0x00, 0xcd)),
+    ("mediumorchid", Color::rgb(0xba, 0x55, 0xd3)),
+    ("mediumpurple", Color::rgb(0x93, 0x70, 0xdb)),
+    ("mediumseagreen", Color::rgb(0x3c, 0xb3, 0x71)),
+    ("mediumslateblue", Color::rgb(0x7b, 0x68, 0xee)),
+    ("mediumspringgreen", Color::rgb(0x00, 0xfa, 0x9a)),
+    ("mediumturquoise", Color::rgb(0x48, 0xd1, 0xcc)),
+    ("mediumvioletred", Color::rgb(0xc7, 0x15, 0x85)),
+    ("midnightblue", Color::rgb(0x19, 0x19, 0x70)),
+    ("mintcream", Color::rgb(0xf5, 0xff, 0xfa)),
+    ("mistyrose", Color::rgb(0xff, 0xe4, 0xe1)),
+    ("moccasin", Color::rgb(0xff, 0xe4, 0xb5)),
+    ("navajowhite", Color::rgb(0xff, 0xde, 0xad)),
+    ("navy", Color::rgb(0x00, 0x00, 0x80)),
+    ("oldlace", Color::rgb(0xfd, 0xf5, 0xe6)),
+    ("olive", Color::rgb(0x80, 0x80, 0x00)),
+    ("olivedrab", Color::rgb(0x6b, 0x8e, 0x23)),
+    ("orange", Color::rgb(0xff, 0xa5, 0x00)),
+    ("orangered", Color::rgb(0xff, 0x45, 0x00)),
+    ("orchid", Color::rgb(0xda, 0x70, 0xd6)),
+    ("palegoldenrod", Color::rgb(0xee, 0xe8, 0xaa)),
+    ("palegreen", Color::rgb(0x98, 0xfb, 0x98)),
+    ("paleturquoise", Color::rgb(0xaf, 0xee, 0xee)),
+    ("palevioletred", Color::rgb(0xdb, 0x70, 0x93)),
+    ("papayawhip", Color::rgb(0xff, 0xef, 0xd5)),
+    ("peachpuff", Color::rgb(0xff, 0xda, 0xb9)),
+    ("peru", Color::rgb(0xcd, 0x85, 0x3f)),
+    ("pink", Color::rgb(0xff, 0xc0, 0xcb)),
+    ("plum", Color::rgb(0xdd, 0xa0, 0xdd)),
+    ("powderblue", Color::rgb(0xb0, 0xe0, 0xe6)),
+    ("purple", Color::rgb(0x80, 0x00, 0x80)),
+    ("rebeccapurple", Color::rgb(0x66, 0x33, 0x99)),
+    ("red", Color::rgb(0xff, 0x00, 0x00)),
+    ("rosybrown", Color::rgb(0xbc, 0x8f, 0x8f)),
+    ("royalblue", Color::rgb(0x41, 0x69, 0xe1)),
+    ("saddlebrown", Color::rgb(0x8b, 0x45, 0x13)),
+    ("salmon", Color::rgb(0xfa, 0x80, 0x72)),
+    ("sandybrown", Color::rgb(0xf4, 0xa4, 0x60)),
+    ("seagreen", Color::rgb(0x2e, 0x8b, 0x57)),
+    ("seashell", Color::rgb(0xff, 0xf5, 0xee)),
+    ("sienna", Color::rgb(0xa0, 0x52, 0x2d)),
+    ("silver", Color::rgb(0xc0, 0xc0, 0xc0)),
+    ("skyblue", Color::rgb(0x87, 0xce, 0xeb)),
+    ("slateblue", Color::rgb(0x6a, 0x5a, 0xcd)),
+    ("slategray", Color::rgb(0x70, 0x80, 0x90)),
+    ("slategrey", Color::rgb(0x70, 0x80, 0x90)),
+    ("snow", Color::rgb(0xff, 0xfa, 0xfa)),
+    ("springgreen", Color::rgb(0x00, 0xff, 0x7f)),
+    ("steelblue", Color::rgb(0x46, 0x82, 0xb4)),
+    ("tan", Color::rgb(0xd2, 0xb4, 0x8c)),
+    ("teal", Color::rgb(0x00, 0x80, 0x80)),
+    ("thistle", Color::rgb(0xd8, 0xbf, 0xd8)),
+    ("tomato", Color::rgb(0xff, 0x63, 0x47)),
+    ("turquoise", Color::rgb(0x40, 0xe0, 0xd0)),
+    ("violet", Color::rgb(0xee, 0x82, 0xee)),
+    ("wheat", Color::rgb(0xf5, 0xde, 0xb3)),
+    ("white", Color::rgb(0xff, 0xff, 0xff)),
+    ("whitesmoke", Color::rgb(0xf5, 0xf5, 0xf5)),
+    ("yellow", Color::rgb(0xff, 0xff, 0x00)),
+    ("yellowgreen", Color::rgb(0x9a, 0xcd, 0x32)),
+];
@@ -0,0 +1,164 @@
+use core::marker::PhantomData;
+
+use crate::{Canvas, Color, Draw, PixelFormat, Shade};
+
+/// Size of the Bayer threshold matrix used by `Ordered`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Bayer {
+    X2,
+    X4,
+    #[default]
+    X8,
+}
+
+impl Bayer {
+    /// Returns the width (and height) of the matrix
+    pub const fn size(self) -> u32 {
+        match self {
+            Self::X2 => 2,
+            Self::X4 => 4,
+            Self::X8 => 8,
+        }
+    }
+
+    /// Returns the threshold at the given position in the `0..size * size` range, positions wrap around.
+    pub const fn threshold(self, x: i32, y: i32) -> u32 {
+        let bits = self.size().trailing_zeros();
+        let x = x.rem_euclid(self.size() as i32) as u32;
+        let y = y.rem_euclid(self.size() as i32) as u32;
+        // Interleaving the bits of (x ^ y) and y and reversing the result gives the recursive Bayer pattern
+        let mut value = 0;
+        let mut bit = 0;
+        while bit < bits {
+            value = (value << 2) | (((x ^ y) >> bit) & 1) << 1 | ((y >> bit) & 1);
+            bit += 1;
+        }
+        value
+    }
+}
+
+/// Brush that quantizes the colors of a `Shade` into the canvas' `PixelFormat` with ordered (Bayer) dithering.
+///
+/// The threshold is picked from the canvas coordinates passed to `Draw::draw`, so the pattern stays fixed to the
+/// canvas no matter which shape is drawn with the brush.
+pub struct Ordered<S: Shade, P: PixelFormat> {
+    pub source: S,
+    pub matrix: Bayer,
+    _marker: PhantomData<P>,
+}
+
+impl<S: Shade, P: PixelFormat> Ordered<S, P> {
+    pub fn new(source: S, matrix: Bayer) -> Self {
+        Self {
+            source,
+            matrix,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the dithered pixel for the given canvas position
+    pub fn pixel(&self, x: i32, y: i32) -> P {
+        let c = self.source.shade(x, y);
+        let n = self.matrix.size() * self.matrix.size();
+        // Threshold in the -0.5..0.5 range
+        let t = (self.matrix.threshold(x, y) as f32 + 0.5) / n as f32 - 0.5;
+        let offset = |c: u8, bits: u8| {
+            let step = 255. / ((1u32 << bits) - 1) as f32;
+            ((c as f32 + t * step).clamp(0., 255.) + 0.5) as u8
+        };
+        let [r, g, b] = P::CHANNEL_BITS;
+        P::from_color(Color::rgba(
+            offset(c.r, r),
+            offset(c.g, g),
+            offset(c.b, b),
+            c.a,
+        ))
+    }
+}
+
+impl<S: Shade, P: PixelFormat> Draw for Ordered<S, P> {
+    type T = P;
+    fn draw(&self, canvas: &mut Canvas<P, &mut [P]>, x: i32, y: i32) {
+        canvas.put(x, y, self.pixel(x, y));
+    }
+}
+
+/// Error-diffusion kernels for `diffuse`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Diffusion {
+    /// Spreads the whole error over 4 neighbours
+    #[default]
+    FloydSteinberg,
+    /// Spreads 3/4 of the error over 6 neighbours, giving higher contrast
+    Atkinson,
+}
+
+impl Diffusion {
+    /// `(dx, dy, weight)` of every neighbour and the divisor of the weights
+    const fn kernel(self) -> (&'static [(i32, usize, i32)], i32) {
+        match self {
+            Self::FloydSteinberg => (&[(1, 0, 7), (-1, 1, 3), (0, 1, 5), (1, 1, 1)], 16),
+            Self::Atkinson => (
+                &[
+                    (1, 0, 1),
+                    (2, 0, 1),
+                    (-1, 1, 1),
+                    (0, 1, 1),
+                    (1, 1, 1),
+                    (0, 2, 1),
+                ],
+                8,
+            ),
+        }
+    }
+
+    /// Length of the `errors` scratch buffer `diffuse` needs for a region that is `w` pixels wide
+    pub const fn scratch_len(w: usize) -> usize {
+        3 * (w + 4)
+    }
+}
+
+/// Draws the region of the given `size` at (`x`, `y`) with the colors of `source`, quantized into the canvas' `PixelFormat`
+/// with error diffusion. The quantization error of each pixel is pushed onto its neighbours in scanline order.
+///
+/// `errors` is scratch space that must hold at least `Diffusion::scratch_len(size.0)` values, it is overwritten.
+pub fn diffuse<P: PixelFormat, S: Shade>(
+    canvas: &mut Canvas<P, &mut [P]>,
+    x: i32,
+    y: i32,
+    size: (usize, usize),
+    source: &S,
+    kernel: Diffusion,
+    errors: &mut [[i16; 3]],
+) {
+    let (w, h) = size;
+    let row_len = w + 4;
+    let errors = &mut errors[..Diffusion::scratch_len(w)];
+    errors.fill([0; 3]);
+    let (neighbours, divisor) = kernel.kernel();
+    for row in 0..h {
+        for col in 0..w {
+            let (cx, cy) = (x + col as i32, y + row as i32);
+            let c = source.shade(cx, cy);
+            // The rows are used as a ring buffer, columns are padded by 2 on both sides
+            let idx = (row % 3) * row_len + col + 2;
+            let e = errors[idx];
+            let clamped = [c.r as i16 + e[0], c.g as i16 + e[1], c.b as i16 + e[2]]
+                .map(|v| v.clamp(0, 255) as u8);
+            let p = P::from_color(Color::rgba(clamped[0], clamped[1], clamped[2], c.a));
+            let actual = p.to_color();
+            let actual = [actual.r, actual.g, actual.b];
+            canvas.put(cx, cy, p);
+
+            for &(dx, dy, weight) in neighbours {
+                let n = ((row + dy) % 3) * row_len + (col as i32 + 2 + dx) as usize;
+                for ch in 0..3 {
+                    errors[n][ch] +=
+                        (clamped[ch] as i16 - actual[ch] as i16) * weight as i16 / divisor as i16;
+                }
+            }
+        }
+        // The row that was just finished becomes the row that is 2 rows ahead
+        errors[(row % 3) * row_len..(row % 3 + 1) * row_len].fill([0; 3]);
+    }
+}
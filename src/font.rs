@@ -0,0 +1,372 @@
+use core::fmt;
+
+/// Order of the pixels within the bytes of a glyph bitmap row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BitOrder {
+    /// The most significant bit is the leftmost pixel, like in PSF and BDF fonts
+    #[default]
+    MsbFirst,
+    /// The least significant bit is the leftmost pixel
+    LsbFirst,
+}
+
+/// The pixels of a `Glyph`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bitmap<'a> {
+    /// Packed rows, top to bottom, every row starts on a new byte
+    Packed(&'a [u8], BitOrder),
+    /// One line of hexadecimal digits per row, top to bottom, with the most significant bit as the leftmost pixel.
+    /// This is how BDF fonts store their glyphs.
+    Hex(&'a str),
+}
+
+/// The 1-bit bitmap of a single character and how it is positioned on a line of text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Glyph<'a> {
+    pub bitmap: Bitmap<'a>,
+    pub width: usize,
+    pub height: usize,
+    /// Position of the top left corner of the bitmap relative to the pen, which is at the top of the line
+    pub offset: (i32, i32),
+    /// Horizontal distance from this glyph's pen position to the next one
+    pub advance: usize,
+}
+
+impl<'a> Glyph<'a> {
+    /// Creates a glyph at the pen position that advances the pen by its width.
+    pub const fn new(bitmap: Bitmap<'a>, width: usize, height: usize) -> Self {
+        Self {
+            bitmap,
+            width,
+            height,
+            offset: (0, 0),
+            advance: width,
+        }
+    }
+
+    /// Returns the number of bytes per bitmap row
+    pub const fn row_bytes(&self) -> usize {
+        self.width.div_ceil(8)
+    }
+
+    /// Returns `true` if the pixel at (`x`, `y`) of the bitmap is set.
+    /// Positions outside of the bitmap, or of a too short or malformed bitmap, are unset.
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        match self.bitmap {
+            Bitmap::Packed(data, order) => {
+                let Some(byte) = data.get(y * self.row_bytes() + x / 8) else {
+                    return false;
+                };
+                let bit = match order {
+                    BitOrder::MsbFirst => 7 - x % 8,
+                    BitOrder::LsbFirst => x % 8,
+                };
+                byte >> bit & 1 == 1
+            }
+            Bitmap::Hex(rows) => {
+                let digit = rows
+                    .lines()
+                    .nth(y)
+                    .and_then(|row| row.trim().as_bytes().get(x / 4))
+                    .and_then(|d| (*d as char).to_digit(16));
+                digit.is_some_and(|d| d >> (3 - x % 4) & 1 == 1)
+            }
+        }
+    }
+}
+
+/// A source of glyphs that `Text` can be rendered with.
+pub trait Font {
+    /// Returns the glyph of `c`, or `None` if the font doesn't have one
+    fn glyph(&self, c: char) -> Option<Glyph<'_>>;
+
+    /// Returns the distance between the tops of two lines of text
+    fn line_height(&self) -> usize;
+
+    /// Returns the glyph that is drawn for `c`, substituting `U+FFFD` or `?` for characters the font doesn't have
+    fn glyph_or_fallback(&self, c: char) -> Option<Glyph<'_>> {
+        self.glyph(c)
+            .or_else(|| self.glyph(char::REPLACEMENT_CHARACTER))
+            .or_else(|| self.glyph('?'))
+    }
+}
+
+/// Error returned when font data can't be parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FontError {
+    /// The data doesn't start with the magic number or keyword of the format
+    InvalidMagic,
+    /// The data ends before everything its header declares
+    Truncated,
+    /// A header field or property is missing or has an invalid value
+    InvalidHeader,
+    /// The data uses a feature of the format that isn't supported
+    Unsupported,
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidMagic => "unknown font format",
+            Self::Truncated => "font data is truncated",
+            Self::InvalidHeader => "invalid font header",
+            Self::Unsupported => "unsupported font feature",
+        })
+    }
+}
+
+/// Fixed-size font whose glyphs are stored one after the other for a contiguous range of characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MonoFont<'a> {
+    /// Glyph bitmaps in the format of `Bitmap::Packed`
+    pub data: &'a [u8],
+    pub glyph_size: (usize, usize),
+    pub bit_order: BitOrder,
+    /// The character of the first glyph in `data`
+    pub first: char,
+}
+
+impl<'a> MonoFont<'a> {
+    pub const fn new(
+        data: &'a [u8],
+        glyph_size: (usize, usize),
+        bit_order: BitOrder,
+        first: char,
+    ) -> Self {
+        Self {
+            data,
+            glyph_size,
+            bit_order,
+            first,
+        }
+    }
+
+    /// Returns the number of bytes per glyph
+    pub const fn glyph_bytes(&self) -> usize {
+        self.glyph_size.0.div_ceil(8) * self.glyph_size.1
+    }
+}
+
+impl Font for MonoFont<'_> {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        let index = (c as u32).checked_sub(self.first as u32)? as usize;
+        let len = self.glyph_bytes();
+        let data = self.data.get(index * len..(index + 1) * len)?;
+        let (w, h) = self.glyph_size;
+        Some(Glyph::new(Bitmap::Packed(data, self.bit_order), w, h))
+    }
+
+    fn line_height(&self) -> usize {
+        self.glyph_size.1
+    }
+}
+
+/// Built-in 8x8 font covering printable ASCII (`' '..='~'`), based on the public domain font8x8 by Daniel Hepper.
+pub const FONT_8X8: MonoFont<'static> =
+    MonoFont::new(&FONT_8X8_DATA, (8, 8), BitOrder::LsbFirst, ' ');
+
+#[rustfmt::skip]
+static FONT_8X8_DATA: [u8; 95 * 8] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // space
+    0x18, 0x3C, 0x3C, 0x18, 0x18, 0x00, 0x18, 0x00, // !
+    0x36, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // "
+    0x36, 0x36, 0x7F, 0x36, 0x7F, 0x36, 0x36, 0x00, // #
+    0x0C, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x0C, 0x00, // $
+    0x00, 0x63, 0x33, 0x18, 0x0C, 0x66, 0x63, 0x00, // %
+    0x1C, 0x36, 0x1C, 0x6E, 0x3B, 0x33, 0x6E, 0x00, // &
+    0x06, 0x06, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, // '
+    0x18, 0x0C, 0x06, 0x06, 0x06, 0x0C, 0x18, 0x00, // (
+    0x06, 0x0C, 0x18, 0x18, 0x18, 0x0C, 0x06, 0x00, // )
+    0x00, 0x66, 0x3C, 0xFF, 0x3C, 0x66, 0x00, 0x00, // *
+    0x00, 0x0C, 0x0C, 0x3F, 0x0C, 0x0C, 0x00, 0x00, // +
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x06, // ,
+    0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00, // -
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00, // .
+    0x60, 0x30, 0x18, 0x0C, 0x06, 0x03, 0x01, 0x00, // /
+    0x3E, 0x63, 0x73, 0x7B, 0x6F, 0x67, 0x3E, 0x00, // 0
+    0x0C, 0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x3F, 0x00, // 1
+    0x1E, 0x33, 0x30, 0x1C, 0x06, 0x33, 0x3F, 0x00, // 2
+    0x1E, 0x33, 0x30, 0x1C, 0x30, 0x33, 0x1E, 0x00, // 3
+    0x38, 0x3C, 0x36, 0x33, 0x7F, 0x30, 0x78, 0x00, // 4
+    0x3F, 0x03, 0x1F, 0x30, 0x30, 0x33, 0x1E, 0x00, // 5
+    0x1C, 0x06, 0x03, 0x1F, 0x33, 0x33, 0x1E, 0x00, // 6
+    0x3F, 0x33, 0x30, 0x18, 0x0C, 0x0C, 0x0C, 0x00, // 7
+    0x1E, 0x33, 0x33, 0x1E, 0x33, 0x33, 0x1E, 0x00, // 8
+    0x1E, 0x33, 0x33, 0x3E, 0x30, 0x18, 0x0E, 0x00, // 9
+    0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x00, // :
+    0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x06, // ;
+    0x18, 0x0C, 0x06, 0x03, 0x06, 0x0C, 0x18, 0x00, // <
+    0x00, 0x00, 0x3F, 0x00, 0x00, 0x3F, 0x00, 0x00, // =
+    0x06, 0x0C, 0x18, 0x30, 0x18, 0x0C, 0x06, 0x00, // >
+    0x1E, 0x33, 0x30, 0x18, 0x0C, 0x00, 0x0C, 0x00, // ?
+    0x3E, 0x63, 0x7B, 0x7B, 0x7B, 0x03, 0x1E, 0x00, // @
+    0x0C, 0x1E, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x00, // A
+    0x3F, 0x66, 0x66, 0x3E, 0x66, 0x66, 0x3F, 0x00, // B
+    0x3C, 0x66, 0x03, 0x03, 0x03, 0x66, 0x3C, 0x00, // C
+    0x1F, 0x36, 0x66, 0x66, 0x66, 0x36, 0x1F, 0x00, // D
+    0x7F, 0x46, 0x16, 0x1E, 0x16, 0x46, 0x7F, 0x00, // E
+    0x7F, 0x46, 0x16, 0x1E, 0x16, 0x06, 0x0F, 0x00, // F
+    0x3C, 0x66, 0x03, 0x03, 0x73, 0x66, 0x7C, 0x00, // G
+    0x33, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x33, 0x00, // H
+    0x1E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00, // I
+    0x78, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E, 0x00, // J
+    0x67, 0x66, 0x36, 0x1E, 0x36, 0x66, 0x67, 0x00, // K
+    0x0F, 0x06, 0x06, 0x06, 0x46, 0x66, 0x7F, 0x00, // L
+    0x63, 0x77, 0x7F, 0x7F, 0x6B, 0x63, 0x63, 0x00, // M
+    0x63, 0x67, 0x6F, 0x7B, 0x73, 0x63, 0x63, 0x00, // N
+    0x1C, 0x36, 0x63, 0x63, 0x63, 0x36, 0x1C, 0x00, // O
+    0x3F, 0x66, 0x66, 0x3E, 0x06, 0x06, 0x0F, 0x00, // P
+    0x1E, 0x33, 0x33, 0x33, 0x3B, 0x1E, 0x38, 0x00, // Q
+    0x3F, 0x66, 0x66, 0x3E, 0x36, 0x66, 0x67, 0x00, // R
+    0x1E, 0x33, 0x07, 0x0E, 0x38, 0x33, 0x1E, 0x00, // S
+    0x3F, 0x2D, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00, // T
+    0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x3F, 0x00, // U
+    0x33, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00, // V
+    0x63, 0x63, 0x63, 0x6B, 0x7F, 0x77, 0x63, 0x00, // W
+    0x63, 0x63, 0x36, 0x1C, 0x1C, 0x36, 0x63, 0x00, // X
+    0x33, 0x33, 0x33, 0x1E, 0x0C, 0x0C, 0x1E, 0x00, // Y
+    0x7F, 0x63, 0x31, 0x18, 0x4C, 0x66, 0x7F, 0x00, // Z
+    0x1E, 0x06, 0x06, 0x06, 0x06, 0x06, 0x1E, 0x00, // [
+    0x03, 0x06, 0x0C, 0x18, 0x30, 0x60, 0x40, 0x00, // \
+    0x1E, 0x18, 0x18, 0x18, 0x18, 0x18, 0x1E, 0x00, // ]
+    0x08, 0x1C, 0x36, 0x63, 0x00, 0x00, 0x00, 0x00, // ^
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, // _
+    0x0C, 0x0C, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, // `
+    0x00, 0x00, 0x1E, 0x30, 0x3E, 0x33, 0x6E, 0x00, // a
+    0x07, 0x06, 0x06, 0x3E, 0x66, 0x66, 0x3B, 0x00, // b
+    0x00, 0x00, 0x1E, 0x33, 0x03, 0x33, 0x1E, 0x00, // c
+    0x38, 0x30, 0x30, 0x3E, 0x33, 0x33, 0x6E, 0x00, // d
+    0x00, 0x00, 0x1E, 0x33, 0x3F, 0x03, 0x1E, 0x00, // e
+    0x1C, 0x36, 0x06, 0x0F, 0x06, 0x06, 0x0F, 0x00, // f
+    0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x1F, // g
+    0x07, 0x06, 0x36, 0x6E, 0x66, 0x66, 0x67, 0x00, // h
+    0x0C, 0x00, 0x0E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00, // i
+    0x30, 0x00, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E, // j
+    0x07, 0x06, 0x66, 0x36, 0x1E, 0x36, 0x67, 0x00, // k
+    0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00, // l
+    0x00, 0x00, 0x33, 0x7F, 0x7F, 0x6B, 0x63, 0x00, // m
+    0x00, 0x00, 0x1F, 0x33, 0x33, 0x33, 0x33, 0x00, // n
+    0x00, 0x00, 0x1E, 0x33, 0x33, 0x33, 0x1E, 0x00, // o
+    0x00, 0x00, 0x3B, 0x66, 0x66, 0x3E, 0x06, 0x0F, // p
+    0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x78, // q
+    0x00, 0x00, 0x3B, 0x6E, 0x66, 0x06, 0x0F, 0x00, // r
+    0x00, 0x00, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x00, // s
+    0x08, 0x0C, 0x3E, 0x0C, 0x0C, 0x2C, 0x18, 0x00, // t
+    0x00, 0x00, 0x33, 0x33, 0x33, 0x33, 0x6E, 0x00, // u
+    0x00, 0x00, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00, // v
+    0x00, 0x00, 0x63, 0x6B, 0x7F, 0x7F, 0x36, 0x00, // w
+    0x00, 0x00, 0x63, 0x36, 0x1C, 0x36, 0x63, 0x00, // x
+    0x00, 0x00, 0x33, 0x33, 0x33, 0x3E, 0x30, 0x1F, // y
+    0x00, 0x00, 0x3F, 0x19, 0x0C, 0x26, 0x3F, 0x00, // z
+    0x38, 0x0C, 0x0C, 0x07, 0x0C, 0x0C, 0x38, 0x00, // {
+    0x18, 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x00, // |
+    0x07, 0x0C, 0x0C, 0x38, 0x0C, 0x0C, 0x07, 0x00, // }
+    0x6E, 0x3B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ~
+];
+
+/// Built-in 5x7 font covering printable ASCII (`' '..='~'`), in the style of the classic character LCD font.
+/// The glyphs are stored in 6x8 cells with an empty column and row, so lines of text need no extra spacing.
+pub const FONT_5X7: MonoFont<'static> =
+    MonoFont::new(&FONT_5X7_DATA, (6, 8), BitOrder::LsbFirst, ' ');
+
+#[rustfmt::skip]
+static FONT_5X7_DATA: [u8; 95 * 8] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // space
+    0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04, 0x00, // !
+    0x0A, 0x0A, 0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, // "
+    0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A, 0x00, // #
+    0x04, 0x1E, 0x05, 0x0E, 0x14, 0x0F, 0x04, 0x00, // $
+    0x03, 0x13, 0x08, 0x04, 0x02, 0x19, 0x18, 0x00, // %
+    0x06, 0x09, 0x05, 0x02, 0x15, 0x09, 0x16, 0x00, // &
+    0x06, 0x04, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, // '
+    0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08, 0x00, // (
+    0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02, 0x00, // )
+    0x00, 0x0A, 0x04, 0x1F, 0x04, 0x0A, 0x00, 0x00, // *
+    0x00, 0x04, 0x04, 0x1F, 0x04, 0x04, 0x00, 0x00, // +
+    0x00, 0x00, 0x00, 0x00, 0x06, 0x04, 0x02, 0x00, // ,
+    0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x00, // -
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x06, 0x00, // .
+    0x00, 0x10, 0x08, 0x04, 0x02, 0x01, 0x00, 0x00, // /
+    0x0E, 0x11, 0x19, 0x15, 0x13, 0x11, 0x0E, 0x00, // 0
+    0x04, 0x06, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, // 1
+    0x0E, 0x11, 0x10, 0x08, 0x04, 0x02, 0x1F, 0x00, // 2
+    0x1F, 0x08, 0x04, 0x08, 0x10, 0x11, 0x0E, 0x00, // 3
+    0x08, 0x0C, 0x0A, 0x09, 0x1F, 0x08, 0x08, 0x00, // 4
+    0x1F, 0x01, 0x0F, 0x10, 0x10, 0x11, 0x0E, 0x00, // 5
+    0x0C, 0x02, 0x01, 0x0F, 0x11, 0x11, 0x0E, 0x00, // 6
+    0x1F, 0x10, 0x08, 0x04, 0x02, 0x02, 0x02, 0x00, // 7
+    0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E, 0x00, // 8
+    0x0E, 0x11, 0x11, 0x1E, 0x10, 0x08, 0x06, 0x00, // 9
+    0x00, 0x06, 0x06, 0x00, 0x06, 0x06, 0x00, 0x00, // :
+    0x00, 0x06, 0x06, 0x00, 0x06, 0x04, 0x02, 0x00, // ;
+    0x10, 0x08, 0x04, 0x02, 0x04, 0x08, 0x10, 0x00, // <
+    0x00, 0x00, 0x1F, 0x00, 0x1F, 0x00, 0x00, 0x00, // =
+    0x01, 0x02, 0x04, 0x08, 0x04, 0x02, 0x01, 0x00, // >
+    0x0E, 0x11, 0x10, 0x08, 0x04, 0x00, 0x04, 0x00, // ?
+    0x0E, 0x11, 0x10, 0x16, 0x15, 0x15, 0x0E, 0x00, // @
+    0x0E, 0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x00, // A
+    0x0F, 0x11, 0x11, 0x0F, 0x11, 0x11, 0x0F, 0x00, // B
+    0x0E, 0x11, 0x01, 0x01, 0x01, 0x11, 0x0E, 0x00, // C
+    0x07, 0x09, 0x11, 0x11, 0x11, 0x09, 0x07, 0x00, // D
+    0x1F, 0x01, 0x01, 0x0F, 0x01, 0x01, 0x1F, 0x00, // E
+    0x1F, 0x01, 0x01, 0x07, 0x01, 0x01, 0x01, 0x00, // F
+    0x0E, 0x11, 0x01, 0x01, 0x19, 0x11, 0x0E, 0x00, // G
+    0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11, 0x00, // H
+    0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, // I
+    0x1C, 0x08, 0x08, 0x08, 0x08, 0x09, 0x06, 0x00, // J
+    0x11, 0x09, 0x05, 0x03, 0x05, 0x09, 0x11, 0x00, // K
+    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x1F, 0x00, // L
+    0x11, 0x1B, 0x15, 0x11, 0x11, 0x11, 0x11, 0x00, // M
+    0x11, 0x11, 0x13, 0x15, 0x19, 0x11, 0x11, 0x00, // N
+    0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, // O
+    0x0F, 0x11, 0x11, 0x0F, 0x01, 0x01, 0x01, 0x00, // P
+    0x0E, 0x11, 0x11, 0x11, 0x15, 0x09, 0x16, 0x00, // Q
+    0x0F, 0x11, 0x11, 0x0F, 0x05, 0x09, 0x11, 0x00, // R
+    0x1E, 0x01, 0x01, 0x0E, 0x10, 0x10, 0x0F, 0x00, // S
+    0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00, // T
+    0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E, 0x00, // U
+    0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04, 0x00, // V
+    0x11, 0x11, 0x11, 0x15, 0x15, 0x1B, 0x11, 0x00, // W
+    0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11, 0x00, // X
+    0x11, 0x11, 0x0A, 0x04, 0x04, 0x04, 0x04, 0x00, // Y
+    0x1F, 0x10, 0x08, 0x04, 0x02, 0x01, 0x1F, 0x00, // Z
+    0x1C, 0x04, 0x04, 0x04, 0x04, 0x04, 0x1C, 0x00, // [
+    0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00, 0x00, // \
+    0x07, 0x04, 0x04, 0x04, 0x04, 0x04, 0x07, 0x00, // ]
+    0x04, 0x0A, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, // ^
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F, 0x00, // _
+    0x02, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, // `
+    0x00, 0x00, 0x0E, 0x10, 0x1E, 0x11, 0x1E, 0x00, // a
+    0x01, 0x01, 0x0D, 0x13, 0x11, 0x11, 0x0F, 0x00, // b
+    0x00, 0x00, 0x0E, 0x01, 0x01, 0x11, 0x0E, 0x00, // c
+    0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1E, 0x00, // d
+    0x00, 0x00, 0x0E, 0x11, 0x1F, 0x01, 0x0E, 0x00, // e
+    0x0C, 0x12, 0x02, 0x07, 0x02, 0x02, 0x02, 0x00, // f
+    0x00, 0x00, 0x1E, 0x11, 0x1E, 0x10, 0x0C, 0x00, // g
+    0x01, 0x01, 0x0D, 0x13, 0x11, 0x11, 0x11, 0x00, // h
+    0x04, 0x00, 0x06, 0x04, 0x04, 0x04, 0x0E, 0x00, // i
+    0x08, 0x00, 0x0C, 0x08, 0x08, 0x09, 0x06, 0x00, // j
+    0x02, 0x02, 0x12, 0x0A, 0x06, 0x0A, 0x12, 0x00, // k
+    0x06, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E, 0x00, // l
+    0x00, 0x00, 0x0B, 0x15, 0x15, 0x11, 0x11, 0x00, // m
+    0x00, 0x00, 0x0D, 0x13, 0x11, 0x11, 0x11, 0x00, // n
+    0x00, 0x00, 0x0E, 0x11, 0x11, 0x11, 0x0E, 0x00, // o
+    0x00, 0x00, 0x0F, 0x11, 0x0F, 0x01, 0x01, 0x00, // p
+    0x00, 0x00, 0x16, 0x19, 0x1E, 0x10, 0x10, 0x00, // q
+    0x00, 0x00, 0x0D, 0x13, 0x01, 0x01, 0x01, 0x00, // r
+    0x00, 0x00, 0x0E, 0x01, 0x0E, 0x10, 0x0F, 0x00, // s
+    0x02, 0x02, 0x07, 0x02, 0x02, 0x12, 0x0C, 0x00, // t
+    0x00, 0x00, 0x11, 0x11, 0x11, 0x19, 0x16, 0x00, // u
+    0x00, 0x00, 0x11, 0x11, 0x11, 0x0A, 0x04, 0x00, // v
+    0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0A, 0x00, // w
+    0x00, 0x00, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x00, // x
+    0x00, 0x00, 0x11, 0x11, 0x1E, 0x10, 0x0E, 0x00, // y
+    0x00, 0x00, 0x1F, 0x08, 0x04, 0x02, 0x1F, 0x00, // z
+    0x08, 0x04, 0x04, 0x02, 0x04, 0x04, 0x08, 0x00, // {
+    0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x00, // |
+    0x02, 0x04, 0x04, 0x08, 0x04, 0x04, 0x02, 0x00, // }
+    0x00, 0x00, 0x02, 0x15, 0x08, 0x00, 0x00, 0x00, // ~
+];
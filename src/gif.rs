@@ -0,0 +1,297 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+use crate::{Color, Image, Palette, PixelFormat, Sink};
+
+/// Largest code of the variable-length LZW codes, which are at most 12 bits long
+const MAX_CODE: u16 = 4095;
+/// "Do not dispose", every frame is drawn over the previous one so that changed sub-rectangles work
+const DISPOSAL_KEEP: u8 = 1 << 2;
+
+/// Collects variable-length codes into bytes, least significant bit first
+struct CodeWriter {
+    out: Vec<u8>,
+    bits: u32,
+    len: u32,
+}
+
+impl CodeWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.bits |= (code as u32) << self.len;
+        self.len += size;
+        while self.len >= 8 {
+            self.out.push(self.bits as u8);
+            self.bits >>= 8;
+            self.len -= 8;
+        }
+    }
+}
+
+/// LZW dictionary as an open addressing hash table from (prefix code, next index) to code
+struct Dictionary {
+    keys: Vec<u32>,
+    codes: Vec<u16>,
+}
+
+impl Dictionary {
+    /// A prime larger than the number of codes keeps the table sparse
+    const SIZE: usize = 5003;
+
+    fn new() -> Self {
+        Self {
+            keys: vec![u32::MAX; Self::SIZE],
+            codes: vec![0; Self::SIZE],
+        }
+    }
+
+    fn clear(&mut self) {
+        self.keys.fill(u32::MAX);
+    }
+
+    /// Returns the slot of `key`, which is either the slot that contains it or the empty slot to insert it into
+    fn slot(&self, key: u32) -> usize {
+        let mut i = key as usize % Self::SIZE;
+        while self.keys[i] != u32::MAX && self.keys[i] != key {
+            i = (i + 1) % Self::SIZE;
+        }
+        i
+    }
+}
+
+/// Compresses palette indices with the variable-length LZW of GIF
+fn lzw(indices: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut out = CodeWriter {
+        out: Vec::new(),
+        bits: 0,
+        len: 0,
+    };
+    let mut dictionary = Dictionary::new();
+    let mut size = min_code_size + 1;
+    let mut next = end + 1;
+    out.write(clear, size);
+
+    if let Some((&first, rest)) = indices.split_first() {
+        let mut prefix = first as u16;
+        for &index in rest {
+            let key = (prefix as u32) << 8 | index as u32;
+            let slot = dictionary.slot(key);
+            if dictionary.keys[slot] == key {
+                prefix = dictionary.codes[slot];
+                continue;
+            }
+            out.write(prefix, size);
+            dictionary.keys[slot] = key;
+            dictionary.codes[slot] = next;
+            if next >= 1 << size {
+                size += 1;
+            }
+            next += 1;
+            if next > MAX_CODE {
+                out.write(clear, size);
+                dictionary.clear();
+                size = min_code_size + 1;
+                next = end + 1;
+            }
+            prefix = index as u16;
+        }
+        out.write(prefix, size);
+    }
+    out.write(end, size);
+    if out.len > 0 {
+        out.out.push(out.bits as u8);
+    }
+    out.out
+}
+
+/// Number of bits needed for the indices of a palette, GIF color tables have 2 to 256 entries
+fn table_bits(colors: usize) -> u32 {
+    colors.next_power_of_two().trailing_zeros().max(1)
+}
+
+/// Writes a color table, padded with black to a power of two
+fn write_table<S: Sink>(sink: &mut S, colors: &[Color]) -> Result<(), S::Error> {
+    let mut table = vec![0; 3 << table_bits(colors.len())];
+    for (entry, c) in table.chunks_exact_mut(3).zip(colors) {
+        entry.copy_from_slice(&[c.r, c.g, c.b]);
+    }
+    sink.write_bytes(&table)
+}
+
+/// Writes data as sub-blocks of up to 255 bytes, followed by the empty block terminator
+fn write_blocks<S: Sink>(sink: &mut S, data: &[u8]) -> Result<(), S::Error> {
+    for block in data.chunks(255) {
+        sink.write_bytes(&[block.len() as u8])?;
+        sink.write_bytes(block)?;
+    }
+    sink.write_bytes(&[0])
+}
+
+/// Encodes a sequence of frames, usually successive `Canvas` buffers, as an animated GIF.
+///
+/// Frames are added with `add_frame`, like `gif.add_frame(&canvas.surface(), 4)`, and the file is completed with
+/// `finish`. By default, every frame gets its own palette built with median-cut quantization (`Palette::median_cut`)
+/// and the animation loops forever.
+pub struct GifEncoder<S: Sink> {
+    sink: S,
+    size: (usize, usize),
+    palette: Option<Palette<Vec<Color>>>,
+    loop_count: Option<u16>,
+    diff: bool,
+    /// Previous frame, compared against with `diff`
+    previous: Option<Vec<Color>>,
+}
+
+impl<S: Sink> GifEncoder<S> {
+    /// Creates an encoder for frames of `size` pixels that writes to `sink`.
+    ///
+    /// Panics if the width or height is larger than 65535, the largest size a GIF can have.
+    pub fn new(sink: S, size: (usize, usize)) -> Self {
+        assert!(
+            size.0 <= u16::MAX as usize && size.1 <= u16::MAX as usize,
+            "GIF size is larger than 65535"
+        );
+        Self {
+            sink,
+            size,
+            palette: None,
+            loop_count: Some(0),
+            diff: false,
+            previous: None,
+        }
+    }
+
+    /// Uses `palette` for all frames instead of building a palette for every frame, only its first 256 colors are
+    /// used. Pixels are mapped to the nearest palette color.
+    pub fn with_palette(self, palette: Palette<Vec<Color>>) -> Self {
+        Self {
+            palette: Some(palette),
+            ..self
+        }
+    }
+
+    /// Sets how many times the animation is repeated after it was played once, `0` repeats it forever, which is the
+    /// default. `None` leaves out the `NETSCAPE2.0` extension so the animation is played only once.
+    pub fn with_loop_count(self, loop_count: Option<u16>) -> Self {
+        Self { loop_count, ..self }
+    }
+
+    /// Encodes only the rectangle that changed since the previous frame, which makes mostly static clips much smaller
+    pub fn with_diff(self, diff: bool) -> Self {
+        Self { diff, ..self }
+    }
+
+    /// Writes the header, the global color table of a fixed palette and the loop count
+    fn write_header(&mut self) -> Result<(), S::Error> {
+        let (w, h) = self.size;
+        self.sink.write_bytes(b"GIF89a")?;
+        self.sink.write_bytes(&(w as u16).to_le_bytes())?;
+        self.sink.write_bytes(&(h as u16).to_le_bytes())?;
+        match &self.palette {
+            Some(palette) => {
+                let colors = &palette.colors[..palette.len().min(256)];
+                let bits = table_bits(colors.len());
+                // Global color table with 8 bits per channel, background color and aspect ratio
+                self.sink.write_bytes(&[0xf0 | (bits - 1) as u8, 0, 0])?;
+                write_table(&mut self.sink, colors)?;
+            }
+            None => self.sink.write_bytes(&[0, 0, 0])?,
+        }
+        if let Some(count) = self.loop_count {
+            self.sink.write_bytes(b"\x21\xff\x0bNETSCAPE2.0\x03\x01")?;
+            self.sink.write_bytes(&count.to_le_bytes())?;
+            self.sink.write_bytes(&[0])?;
+        }
+        Ok(())
+    }
+
+    /// Adds a frame that is shown for `delay` hundredths of a second, the unit of GIF.
+    ///
+    /// Panics if the size of `frame` isn't the size of the encoder.
+    pub fn add_frame<P: PixelFormat, B: AsRef<[P]>>(
+        &mut self,
+        frame: &Image<P, B>,
+        delay: u16,
+    ) -> Result<(), S::Error> {
+        assert_eq!(frame.size(), self.size, "frame size doesn't match the GIF");
+        let (w, h) = self.size;
+        let colors: Vec<Color> = frame
+            .rows()
+            .flatten()
+            .map(|p| p.to_color().with_alpha(255))
+            .collect();
+
+        // The changed rectangle as (x, y, w, h), a frame without changes still needs one pixel to carry the delay,
+        // unless the frames are empty
+        let mut rect = (0, 0, w, h);
+        match &self.previous {
+            Some(previous) if self.diff => {
+                let changed = |i: usize| colors[i] != previous[i];
+                let rows: Vec<usize> = (0..h)
+                    .filter(|&y| (0..w).any(|x| changed(x + y * w)))
+                    .collect();
+                rect = match (rows.first(), rows.last()) {
+                    (Some(&top), Some(&bottom)) => {
+                        let columns = |x: usize| (top..=bottom).any(|y| changed(x + y * w));
+                        let left = (0..w).find(|&x| columns(x)).unwrap();
+                        let right = (0..w).rev().find(|&x| columns(x)).unwrap();
+                        (left, top, right - left + 1, bottom - top + 1)
+                    }
+                    _ => (0, 0, w.min(1), h.min(1)),
+                };
+            }
+            None => self.write_header()?,
+            _ => {}
+        }
+        let (x0, y0, rw, rh) = rect;
+        let pixels = (y0..y0 + rh).flat_map(|y| (x0..x0 + rw).map(move |x| x + y * w));
+
+        let local = match &self.palette {
+            Some(_) => None,
+            None => Some(Palette::median_cut(pixels.clone().map(|i| colors[i]), 256)),
+        };
+        let palette = local.as_ref().or(self.palette.as_ref()).unwrap();
+        let mut cache = BTreeMap::new();
+        let indices: Vec<u8> = pixels
+            .map(|i| {
+                *cache
+                    .entry(colors[i].to_u32())
+                    .or_insert_with(|| palette.nearest(colors[i]))
+            })
+            .collect();
+
+        // Graphic control extension with the delay
+        self.sink.write_bytes(&[0x21, 0xf9, 4, DISPOSAL_KEEP])?;
+        self.sink.write_bytes(&delay.to_le_bytes())?;
+        self.sink.write_bytes(&[0, 0])?;
+
+        self.sink.write_bytes(&[0x2c])?;
+        for v in [x0, y0, rw, rh] {
+            self.sink.write_bytes(&(v as u16).to_le_bytes())?;
+        }
+        let len = palette.len().min(256);
+        let bits = table_bits(len);
+        match &local {
+            Some(local) => {
+                self.sink.write_bytes(&[0x80 | (bits - 1) as u8])?;
+                write_table(&mut self.sink, &local.colors)?;
+            }
+            None => self.sink.write_bytes(&[0])?,
+        }
+        let min_code_size = bits.max(2);
+        self.sink.write_bytes(&[min_code_size as u8])?;
+        write_blocks(&mut self.sink, &lzw(&indices, min_code_size))?;
+
+        self.previous = Some(colors);
+        Ok(())
+    }
+
+    /// Writes the trailer and returns the sink
+    pub fn finish(mut self) -> Result<S, S::Error> {
+        if self.previous.is_none() {
+            self.write_header()?;
+        }
+        self.sink.write_bytes(&[0x3b])?;
+        Ok(self.sink)
+    }
+}
@@ -0,0 +1,215 @@
+use core::f64::consts::TAU;
+
+use crate::{math, BlendSpace, Canvas, Color, ColorSpace, Draw, Rgba, Shade};
+
+/// A color at a position (`offset`) along a gradient, offsets are usually in the `0..=1` range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl Stop {
+    pub const fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// What a gradient does outside of the `0..=1` range of its geometry.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Extend {
+    /// Keeps the color of the first or last stop
+    #[default]
+    Pad,
+    /// Repeats the gradient
+    Repeat,
+    /// Repeats the gradient, mirroring every other repetition
+    Reflect,
+}
+
+/// The geometry of a `Gradient`, all positions are in canvas coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientShape {
+    /// Varies along the line from `start` to `end`
+    Linear { start: (f32, f32), end: (f32, f32) },
+    /// Varies with the distance from `center`, reaching offset 1 at `radius`
+    Radial { center: (f32, f32), radius: f32 },
+    /// Varies with the angle around `center`, starting at `angle` radians and going clockwise on the screen
+    Conic { center: (f32, f32), angle: f32 },
+}
+
+/// `draw`able gradient with multiple color stops.
+///
+/// Gradients compute their color from the canvas coordinates they are drawn at, so they can be used as the brush
+/// of any shape, e.g. `canvas.rect(0, 0, 100, 100, &gradient)`. Translucent colors are blended over the canvas.
+///
+/// On `u32` canvases gradients can be drawn directly, for other pixel formats they implement `Shade` and can be
+/// drawn through a dithering brush like `Ordered`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gradient<'a> {
+    pub shape: GradientShape,
+    /// Stops sorted by their offset
+    pub stops: &'a [Stop],
+    pub extend: Extend,
+    /// The space the stops are interpolated in, `ColorSpace::Linear` also makes translucent colors blend in linear light
+    pub space: ColorSpace,
+}
+
+impl<'a> Gradient<'a> {
+    /// Creates a gradient that varies along the line from (`x0`, `y0`) to (`x1`, `y1`)
+    pub const fn linear(x0: f32, y0: f32, x1: f32, y1: f32, stops: &'a [Stop]) -> Self {
+        Self::new(
+            GradientShape::Linear {
+                start: (x0, y0),
+                end: (x1, y1),
+            },
+            stops,
+        )
+    }
+
+    /// Creates a circular gradient around (`cx`, `cy`)
+    pub const fn radial(cx: f32, cy: f32, radius: f32, stops: &'a [Stop]) -> Self {
+        Self::new(
+            GradientShape::Radial {
+                center: (cx, cy),
+                radius,
+            },
+            stops,
+        )
+    }
+
+    /// Creates a sweep gradient around (`cx`, `cy`) that starts at `angle` radians
+    pub const fn conic(cx: f32, cy: f32, angle: f32, stops: &'a [Stop]) -> Self {
+        Self::new(
+            GradientShape::Conic {
+                center: (cx, cy),
+                angle,
+            },
+            stops,
+        )
+    }
+
+    /// Creates a gradient with `Extend::Pad` that interpolates in `ColorSpace::Srgb`
+    pub const fn new(shape: GradientShape, stops: &'a [Stop]) -> Self {
+        Self {
+            shape,
+            stops,
+            extend: Extend::Pad,
+            space: ColorSpace::Srgb,
+        }
+    }
+
+    /// Returns a copy of `self` with a different `Extend` mode
+    pub const fn with_extend(self, extend: Extend) -> Self {
+        Self { extend, ..self }
+    }
+
+    /// Returns a copy of `self` that interpolates in a different `ColorSpace`
+    pub const fn with_space(self, space: ColorSpace) -> Self {
+        Self { space, ..self }
+    }
+
+    /// Returns the gradient offset at a canvas position before the `Extend` mode is applied.
+    /// Positions are sampled at the center of the canvas pixel.
+    pub fn offset(&self, x: i32, y: i32) -> f32 {
+        let (x, y) = (x as f64 + 0.5, y as f64 + 0.5);
+        match self.shape {
+            GradientShape::Linear { start, end } => {
+                let (dx, dy) = ((end.0 - start.0) as f64, (end.1 - start.1) as f64);
+                let len = dx * dx + dy * dy;
+                if len == 0. {
+                    return 0.;
+                }
+                (((x - start.0 as f64) * dx + (y - start.1 as f64) * dy) / len) as f32
+            }
+            GradientShape::Radial { center, radius } => {
+                let (dx, dy) = (x - center.0 as f64, y - center.1 as f64);
+                if radius <= 0. {
+                    return 1.;
+                }
+                (math::sqrt(dx * dx + dy * dy) / radius as f64) as f32
+            }
+            GradientShape::Conic { center, angle } => {
+                let a = math::atan2(y - center.1 as f64, x - center.0 as f64) - angle as f64;
+                let t = a / TAU;
+                (t - math::floor(t)) as f32
+            }
+        }
+    }
+
+    /// Returns the color at offset `t` after applying the `Extend` mode
+    pub fn color_at(&self, t: f32) -> Color {
+        let t = t as f64;
+        let t = match self.extend {
+            Extend::Pad => t,
+            Extend::Repeat => t - math::floor(t),
+            Extend::Reflect => {
+                let t = t - math::floor(t / 2.) * 2.;
+                if t > 1. {
+                    2. - t
+                } else {
+                    t
+                }
+            }
+        } as f32;
+
+        let (first, last) = match self.stops {
+            [] => return Color::TRANSPARENT,
+            [first, .., last] => (first, last),
+            [only] => return only.color,
+        };
+        // NaN offsets, like those of a radial gradient with a NaN radius, get the first color
+        if t <= first.offset || t.is_nan() {
+            return first.color;
+        }
+        if t >= last.offset {
+            return last.color;
+        }
+        // Clamped so that stops with NaN offsets can't move the lookup out of the slice
+        let i = self
+            .stops
+            .partition_point(|s| s.offset <= t)
+            .clamp(1, self.stops.len() - 1);
+        let (a, b) = (self.stops[i - 1], self.stops[i]);
+        let local = (t - a.offset) / (b.offset - a.offset);
+        match self.space {
+            // The lookup tables are a lot cheaper than the float conversions in `Color::lerp`
+            ColorSpace::Srgb | ColorSpace::Linear => {
+                let space = self.blend_space();
+                let t = math::round(local as f64 * 255.) as u8;
+                Color::rgba(
+                    space.mix(a.color.r, b.color.r, t),
+                    space.mix(a.color.g, b.color.g, t),
+                    space.mix(a.color.b, b.color.b, t),
+                    BlendSpace::Srgb.mix(a.color.a, b.color.a, t),
+                )
+            }
+            space => a.color.lerp(b.color, local, space),
+        }
+    }
+
+    fn blend_space(&self) -> BlendSpace {
+        match self.space {
+            ColorSpace::Linear => BlendSpace::Linear,
+            _ => BlendSpace::Srgb,
+        }
+    }
+}
+
+impl Shade for Gradient<'_> {
+    fn shade(&self, canvas_x: i32, canvas_y: i32) -> Color {
+        self.color_at(self.offset(canvas_x, canvas_y))
+    }
+}
+
+impl Draw for Gradient<'_> {
+    type T = u32;
+    fn draw(&self, canvas: &mut Canvas<u32, &mut [u32]>, x: i32, y: i32) {
+        let c = self.shade(x, y);
+        Rgba {
+            space: self.blend_space(),
+            ..Rgba::from(c)
+        }
+        .draw(canvas, x, y);
+    }
+}
@@ -0,0 +1,220 @@
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Canvas, Draw};
+
+/// A rectangular grid of pixels that can be `draw`n onto a `Canvas`.
+///
+/// Like `Canvas`, an `Image` can either own its data (e.g. a `Vec<T>`) or just borrow it (e.g. a `&[T]` of asset
+/// data embedded in the binary). Rows are `stride` elements apart, which allows an `Image` to be a view into a
+/// larger buffer, see `Image::region`.
+///
+/// Flipping an `Image` doesn't touch its data, it only changes how the pixels are read.
+#[derive(Clone, Debug)]
+pub struct Image<T, B: AsRef<[T]>> {
+    pub data: B,
+    width: usize,
+    height: usize,
+    stride: usize,
+    flip_h: bool,
+    flip_v: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T, B: AsRef<[T]>> Image<T, B> {
+    /// Creates a new `Image` whose rows are packed without any padding.
+    ///
+    /// Panics if `data` is shorter than `width * height`.
+    pub fn new(data: B, width: usize, height: usize) -> Self {
+        Self::with_stride(data, width, height, width)
+    }
+
+    /// Creates a new `Image` whose rows start `stride` elements apart.
+    ///
+    /// Panics if `stride < width` or if `data` is too short to hold `height` rows.
+    pub fn with_stride(data: B, width: usize, height: usize, stride: usize) -> Self {
+        assert!(stride >= width, "image stride is smaller than its width");
+        if width > 0 && height > 0 {
+            assert!(
+                data.as_ref().len() >= (height - 1) * stride + width,
+                "image data is too short"
+            );
+        }
+        Self {
+            data,
+            width,
+            height,
+            stride,
+            flip_h: false,
+            flip_v: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the distance between the start of two rows in `data`
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns `(width, height)`
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Returns `true` if the image has no pixels
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns `true` if the image is flipped horizontally
+    pub fn is_flipped_h(&self) -> bool {
+        self.flip_h
+    }
+
+    /// Returns `true` if the image is flipped vertically
+    pub fn is_flipped_v(&self) -> bool {
+        self.flip_v
+    }
+
+    /// Returns the image mirrored along its vertical axis
+    pub fn flipped_h(self) -> Self {
+        Self {
+            flip_h: !self.flip_h,
+            ..self
+        }
+    }
+
+    /// Returns the image mirrored along its horizontal axis
+    pub fn flipped_v(self) -> Self {
+        Self {
+            flip_v: !self.flip_v,
+            ..self
+        }
+    }
+
+    /// Returns the index into `data` of the pixel at (`x`, `y`) as it is displayed, taking flipping into account
+    fn index(&self, x: usize, y: usize) -> usize {
+        let x = if self.flip_h { self.width - 1 - x } else { x };
+        let y = if self.flip_v { self.height - 1 - y } else { y };
+        x + y * self.stride
+    }
+
+    /// Returns a reference to the pixel at (`x`, `y`), or `None` if the position is outside of the image
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.data.as_ref()[self.index(x, y)])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an `Image` that borrows the data of `self`
+    pub fn borrowed(&self) -> Image<T, &[T]> {
+        Image {
+            data: self.data.as_ref(),
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+            flip_h: self.flip_h,
+            flip_v: self.flip_v,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a view of the `w`x`h` sub-rectangle at (`x`, `y`), in displayed (possibly flipped) coordinates.
+    /// The rectangle is clipped to the bounds of the image.
+    pub fn region(&self, x: usize, y: usize, w: usize, h: usize) -> Image<T, &[T]> {
+        self.borrowed().into_region(x, y, w, h)
+    }
+
+    /// Returns an iterator over the displayed rows of the image
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.height)
+            .map(move |y| (0..self.width).map(move |x| &self.data.as_ref()[self.index(x, y)]))
+    }
+}
+
+impl<T> Image<T, &[T]> {
+    /// Like `region`, but consumes the view so that the result borrows the original data instead of `self`
+    pub fn into_region(self, x: usize, y: usize, w: usize, h: usize) -> Self {
+        let x = x.min(self.width);
+        let y = y.min(self.height);
+        let w = w.min(self.width - x);
+        let h = h.min(self.height - y);
+        if w == 0 || h == 0 {
+            return Image::new(&[], 0, 0);
+        }
+        // Convert the displayed rectangle into the rectangle in `data`
+        let data_x = if self.flip_h { self.width - x - w } else { x };
+        let data_y = if self.flip_v { self.height - y - h } else { y };
+        let start = data_x + data_y * self.stride;
+        let end = start + (h - 1) * self.stride + w;
+        Image {
+            data: &self.data[start..end],
+            width: w,
+            height: h,
+            stride: self.stride,
+            flip_h: self.flip_h,
+            flip_v: self.flip_v,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
+    /// Returns an `Image` view of the whole surface, e.g. to encode the current frame.
+    /// If the buffer is shorter than the surface, the view only has the rows that are complete in the buffer.
+    pub fn surface(&self) -> Image<T, &[T]> {
+        let (w, h) = self.surface_size;
+        let buf = self.buf.as_ref();
+        let h = buf.len().checked_div(w).map_or(h, |rows| rows.min(h));
+        Image::new(buf, w, h)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone, B: AsRef<[T]>> Image<T, B> {
+    /// Copies the displayed pixels into a new, tightly packed and unflipped `Image`
+    pub fn to_owned_image(&self) -> Image<T, Vec<T>> {
+        let data: Vec<T> = self.rows().flatten().cloned().collect();
+        Image::new(data, self.width, self.height)
+    }
+
+    /// Returns a copy of the image resized to `w`x`h` with nearest-neighbour sampling.
+    /// Resizing an empty image, or resizing to an empty size, returns an empty image.
+    pub fn scaled(&self, w: usize, h: usize) -> Image<T, Vec<T>> {
+        if self.is_empty() || w == 0 || h == 0 {
+            return Image::new(Vec::new(), 0, 0);
+        }
+        let mut data = Vec::with_capacity(w * h);
+        for y in 0..h {
+            let src_y = y * self.height / h;
+            for x in 0..w {
+                let src_x = x * self.width / w;
+                data.push(self.data.as_ref()[self.index(src_x, src_y)].clone());
+            }
+        }
+        Image::new(data, w, h)
+    }
+}
+
+impl<T: Clone, B: AsRef<[T]>> Draw for Image<T, B> {
+    type T = T;
+    fn draw(&self, canvas: &mut Canvas<Self::T, &mut [Self::T]>, start_x: i32, start_y: i32) {
+        for (y, row) in self.rows().enumerate() {
+            for (x, c) in row.enumerate() {
+                canvas.put(start_x + x as i32, start_y + y as i32, c.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    sink::{write_sink, Batch},
+    Image, PixelFormat, Sink,
+};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+/// Pixel bytes per escape sequence, the protocol allows at most 4096 bytes of base64 in one chunk
+const CHUNK_LEN: usize = 4096 / 4 * 3;
+
+/// Options of `write_kitty`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KittyOptions {
+    /// Sends 32-bit RGBA pixels, otherwise 24-bit RGB pixels are sent and the image is opaque
+    pub alpha: bool,
+}
+
+/// Writes up to 3 bytes as base64, padded with `=`
+fn write_base64<S: Sink>(out: &mut Batch<S>, bytes: &[u8]) -> Result<(), S::Error> {
+    let n = bytes.iter().fold(0, |n, &b| n << 8 | b as u32) << (8 * (3 - bytes.len()));
+    for i in 0..4 {
+        if i <= bytes.len() {
+            out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize])?;
+        } else {
+            out.push(b'=')?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes `image` as escape sequences of the Kitty graphics protocol that transmit the raw pixels and display them
+/// at the cursor, in kitty, WezTerm, Ghostty and other terminals that support it.
+///
+/// The pixels are split into chunks of 4096 base64 characters as the protocol requires. Responses of the terminal
+/// are suppressed (`q=2`), so nothing is sent back to the input of the program. This doesn't allocate, a `Canvas`
+/// can be written with `write_kitty(&mut sink, &canvas.surface(), options)`.
+pub fn write_kitty<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<P, B>,
+    options: KittyOptions,
+) -> Result<(), S::Error> {
+    let (w, h) = image.size();
+    let (format, channels) = if options.alpha { (32, 4) } else { (24, 3) };
+    let mut bytes = image.rows().flatten().flat_map(|p| {
+        let c = p.to_color();
+        [c.r, c.g, c.b, c.a].into_iter().take(channels)
+    });
+
+    let mut remaining = w * h * channels;
+    let mut first = true;
+    loop {
+        let len = remaining.min(CHUNK_LEN);
+        remaining -= len;
+        let more = remaining > 0;
+        if first {
+            write_sink!(
+                sink,
+                "\x1b_Ga=T,f={format},s={w},v={h},q=2,m={}",
+                more as u8
+            )?;
+        } else {
+            write_sink!(sink, "\x1b_Gm={}", more as u8)?;
+        }
+        first = false;
+
+        let mut out = Batch::new(sink);
+        out.push(b';')?;
+        for start in (0..len).step_by(3) {
+            let mut group = [0; 3];
+            let n = (len - start).min(3);
+            for b in &mut group[..n] {
+                *b = bytes.next().unwrap_or(0);
+            }
+            write_base64(&mut out, &group[..n])?;
+        }
+        out.extend(b"\x1b\\")?;
+        out.flush()?;
+        if !more {
+            return Ok(());
+        }
+    }
+}
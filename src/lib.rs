@@ -1,7 +1,87 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::marker::PhantomData;
 
+mod ansi;
+mod bdf;
+mod blend;
+mod blit;
+mod bmp;
+mod braille;
+mod color;
+mod dither;
+mod font;
+#[cfg(feature = "alloc")]
+mod gif;
+mod gradient;
+mod image;
+mod kitty;
+mod math;
+mod netpbm;
+mod palette;
+mod pixel;
+#[cfg(feature = "alloc")]
+mod png;
+mod psf;
+mod qoi;
+mod resample;
+mod sink;
+mod sixel;
+mod sprite;
+#[cfg(feature = "std")]
+pub mod testing;
+mod text;
+mod transform;
+#[cfg(feature = "truetype")]
+mod truetype;
+mod y4m;
+#[cfg(feature = "alloc")]
+mod zlib;
+
+pub use ansi::AnsiColors;
+#[cfg(feature = "alloc")]
+pub use ansi::AnsiPresenter;
+pub use bdf::BdfFont;
+pub use blend::{blend, linear_to_srgb, srgb_to_linear, BlendSpace, Rgba, LINEAR_MAX};
+pub use blit::{Bitwise, BlitOptions, Invert, RasterOp, Xor};
+pub use bmp::{write_bmp, Bmp, BmpOptions, DecodeError};
+pub use braille::BrailleRenderer;
+pub use color::{Color, ColorSpace, ParseColorError, NAMED_COLORS};
+pub use dither::{diffuse, Bayer, Diffusion, Ordered};
+pub use font::{BitOrder, Bitmap, Font, FontError, Glyph, MonoFont, FONT_5X7, FONT_8X8};
+#[cfg(feature = "alloc")]
+pub use gif::GifEncoder;
+pub use gradient::{Extend, Gradient, GradientShape, Stop};
+pub use image::Image;
+pub use kitty::{write_kitty, KittyOptions};
+#[cfg(feature = "alloc")]
+pub use netpbm::read_ppm;
+pub use netpbm::{write_pam, write_pgm, write_ppm, PnmEncoding};
+pub use palette::{Cycle, Index, Palette};
+pub use pixel::{Gray4, Gray8, PixelFormat, Rgb565, Shade};
+#[cfg(feature = "alloc")]
+pub use png::{write_png, write_png_indexed, Png, PngColor};
+pub use psf::PsfFont;
+pub use qoi::{write_qoi, Qoi, QoiOptions};
+pub use resample::Filter;
+#[cfg(feature = "std")]
+pub use sink::IoSink;
+pub use sink::{BufferFull, FmtSink, Sink, SliceSink};
+#[cfg(feature = "alloc")]
+pub use sixel::write_sixel;
+pub use sixel::write_sixel_indexed;
+pub use sprite::{AnimatedSprite, Sprite, SpriteSheet, Transparency};
+pub use text::{Align, PositionedGlyph, Text, TextBox, VerticalAlign};
+pub use transform::{Affine, Sampling, Transformed};
+#[cfg(feature = "truetype")]
+pub use truetype::{GlyphBitmap, GlyphCache, OutlineText, TrueTypeFont};
+pub use y4m::{Chroma, Y4mEncoder};
+
 /// Main entry point of `framebrush`, a `Canvas` can be constructed with `Canvas::new`.
 ///
 /// `Canvas::new` doesn't perform any allocations and only does some calculations for resizing.
@@ -100,6 +180,16 @@ impl<T: Clone, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
         self.buf.as_mut()
     }
 
+    /// Returns the size of the surface (the buffer) in pixels
+    pub fn surface_size(&self) -> (usize, usize) {
+        self.surface_size
+    }
+
+    /// Returns the size of the canvas in canvas pixels
+    pub fn canvas_size(&self) -> (usize, usize) {
+        self.canvas_size
+    }
+
     /// Returns a `Canvas` that borrows the current canvas' buffer and has the same size data.
     /// This method is used to create a new temporary canvas that can interface with the `Draw` API.
     pub fn borrowed(&mut self) -> Canvas<T, &mut [T]> {
@@ -216,6 +306,12 @@ impl<T: Clone, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
 
     /// 'Put's a value to the specified position on the canvas
     pub fn put(&mut self, x: i32, y: i32, val: T) {
+        self.put_with(x, y, |_| val.clone());
+    }
+
+    /// Like `put`, but computes the new value of every surface pixel covered by the canvas position from its
+    /// current value. This is used by brushes that read back the buffer, like compositing brushes.
+    pub fn put_with<F: FnMut(&T) -> T>(&mut self, x: i32, y: i32, mut f: F) {
         #[cfg(not(feature = "wrap"))]
         {
             let x = {
@@ -240,7 +336,8 @@ impl<T: Clone, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
                     round((x + 1) as f32 * self.ratio.0) as usize + y_idx * self.surface_size.0;
                 for idx in start..end {
                     if idx < (y_idx + 1) * self.surface_size.0 && idx < self.buf.as_mut().len() {
-                        self.buf.as_mut()[idx] = val.clone();
+                        let buf = self.buf.as_mut();
+                        buf[idx] = f(&buf[idx]);
                     }
                 }
             }
@@ -261,7 +358,8 @@ impl<T: Clone, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
                     let y_idx = y_idx % self.surface_size.1;
                     let idx = x_idx + y_idx * self.surface_size.0;
                     if idx < (y_idx + 1) * self.surface_size.0 && idx < self.buf.as_mut().len() {
-                        self.set(x_idx, y_idx, val.clone());
+                        let buf = self.buf.as_mut();
+                        buf[idx] = f(&buf[idx]);
                     }
                 }
             }
@@ -276,6 +374,12 @@ impl<T: Clone, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
 
 /// Primitive `draw`able shape that holds a single value that can be `put` onto the `Canvas`.
 pub struct Pixel<T: Clone>(T);
+impl<T: Clone> Pixel<T> {
+    /// Creates a shape that `put`s `value`
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
 impl<P: Clone> Draw for Pixel<P> {
     type T = P;
 
@@ -295,14 +399,12 @@ impl<P: Clone, D: Draw<T = P>> Draw for Rect<'_, D> {
     type T = P;
 
     fn draw(&self, canvas: &mut Canvas<Self::T, &mut [Self::T]>, x: i32, y: i32) {
-        let mut y_counter = y;
-        for _ in 0..self.h {
-            let mut x_counter = x;
-            for _ in 0..self.w {
+        let y_end = y.saturating_add(self.h.min(i32::MAX as usize) as i32);
+        let x_end = x.saturating_add(self.w.min(i32::MAX as usize) as i32);
+        for y_counter in y..y_end {
+            for x_counter in x..x_end {
                 self.d.draw(canvas, x_counter, y_counter);
-                x_counter += 1;
             }
-            y_counter += 1;
         }
     }
 }
@@ -0,0 +1,167 @@
+// `core` doesn't provide transcendental float functions, so the few that framebrush needs are
+// implemented here. They are `const fn` so that lookup tables can be built at compile time.
+
+const LN_2: f64 = core::f64::consts::LN_2;
+
+/// Natural logarithm. Returns `f64::NEG_INFINITY` for `x <= 0`.
+pub(crate) const fn ln(x: f64) -> f64 {
+    if x <= 0. {
+        return f64::NEG_INFINITY;
+    }
+    // Split x into m * 2^e with m in [1, 2)
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+
+    // ln(m) = 2 * atanh((m - 1) / (m + 1)), z is at most 1/3 so the series converges quickly
+    let z = (m - 1.) / (m + 1.);
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = 0.;
+    let mut k = 1.;
+    while k < 40. {
+        sum += term / k;
+        term *= z2;
+        k += 2.;
+    }
+    2. * sum + e as f64 * LN_2
+}
+
+/// Exponential function
+pub(crate) const fn exp(x: f64) -> f64 {
+    if x < -708. {
+        return 0.;
+    }
+    if x > 709. {
+        return f64::INFINITY;
+    }
+    // x = k * ln(2) + r with |r| <= ln(2) / 2
+    let k = round(x / LN_2);
+    let r = x - k * LN_2;
+    let mut term = 1.;
+    let mut sum = 1.;
+    let mut n = 1.;
+    while n < 20. {
+        term *= r / n;
+        sum += term;
+        n += 1.;
+    }
+    sum * f64::from_bits(((k as i64 + 1023) as u64) << 52)
+}
+
+/// `x` raised to the power of `y` for `x >= 0`
+pub(crate) const fn powf(x: f64, y: f64) -> f64 {
+    if x <= 0. {
+        0.
+    } else {
+        exp(y * ln(x))
+    }
+}
+
+/// Rounds half away from zero
+pub(crate) const fn round(x: f64) -> f64 {
+    if x >= 0. {
+        (x + 0.5) as i64 as f64
+    } else {
+        (x - 0.5) as i64 as f64
+    }
+}
+
+/// Largest integer less than or equal to `x`
+pub(crate) const fn floor(x: f64) -> f64 {
+    let t = x as i64 as f64;
+    if t > x {
+        t - 1.
+    } else {
+        t
+    }
+}
+
+/// Square root, returns 0 for `x <= 0`
+pub(crate) const fn sqrt(x: f64) -> f64 {
+    if x <= 0. {
+        return 0.;
+    }
+    // Halving the exponent gives a good initial guess for Newton's method
+    let mut r = f64::from_bits((x.to_bits() >> 1) + (1023 << 51));
+    let mut i = 0;
+    while i < 6 {
+        r = 0.5 * (r + x / r);
+        i += 1;
+    }
+    r
+}
+
+/// Cube root
+pub(crate) const fn cbrt(x: f64) -> f64 {
+    if x < 0. {
+        -powf(-x, 1. / 3.)
+    } else {
+        powf(x, 1. / 3.)
+    }
+}
+
+/// Arctangent in radians
+pub(crate) const fn atan(x: f64) -> f64 {
+    if x < 0. {
+        return -atan(-x);
+    }
+    if x > 1. {
+        return core::f64::consts::FRAC_PI_2 - atan(1. / x);
+    }
+    // atan(x) = 2 * atan(x / (1 + sqrt(1 + x^2))), applied twice to make the series converge quickly
+    let x = x / (1. + sqrt(1. + x * x));
+    let x = x / (1. + sqrt(1. + x * x));
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = 0.;
+    let mut k = 1.;
+    while k < 30. {
+        sum += term / k;
+        term *= -x2;
+        k += 2.;
+    }
+    4. * sum
+}
+
+/// Four-quadrant arctangent of `y / x` in radians, in the `-PI..=PI` range
+pub(crate) const fn atan2(y: f64, x: f64) -> f64 {
+    use core::f64::consts::{FRAC_PI_2, PI};
+    if x > 0. {
+        atan(y / x)
+    } else if x < 0. {
+        if y >= 0. {
+            atan(y / x) + PI
+        } else {
+            atan(y / x) - PI
+        }
+    } else if y > 0. {
+        FRAC_PI_2
+    } else if y < 0. {
+        -FRAC_PI_2
+    } else {
+        0.
+    }
+}
+
+/// Sine of `x` radians
+pub(crate) const fn sin(x: f64) -> f64 {
+    use core::f64::consts::{PI, TAU};
+    // Reduce to -PI..PI, where the series converges quickly
+    let x = x - floor((x + PI) / TAU) * TAU;
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = 0.;
+    let mut n = 1.;
+    while n < 30. {
+        sum += term;
+        term *= -x2 / ((n + 1.) * (n + 2.));
+        n += 2.;
+    }
+    sum
+}
+
+/// Cosine of `x` radians
+pub(crate) const fn cos(x: f64) -> f64 {
+    sin(x + core::f64::consts::FRAC_PI_2)
+}
@@ -0,0 +1,161 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    sink::{write_sink, Batch},
+    Image, PixelFormat, Sink,
+};
+#[cfg(feature = "alloc")]
+use crate::{Color, DecodeError};
+
+/// How the samples of a PPM or PGM image are stored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PnmEncoding {
+    /// One byte per sample, the compact "raw" variant (`P6`/`P5`)
+    #[default]
+    Binary,
+    /// Decimal numbers separated by whitespace (`P3`/`P2`), readable as text and writable to a `FmtSink`
+    Ascii,
+}
+
+/// Netpbm recommends keeping the lines of ASCII images at most this long
+const MAX_LINE_LEN: usize = 70;
+
+/// Writes the 8-bit samples that follow the header in the given encoding
+fn write_samples<S: Sink, I: Iterator<Item = u8>>(
+    sink: &mut S,
+    samples: I,
+    encoding: PnmEncoding,
+) -> Result<(), S::Error> {
+    match encoding {
+        PnmEncoding::Binary => {
+            let mut batch = Batch::new(sink);
+            samples
+                .into_iter()
+                .try_for_each(|sample| batch.push(sample))?;
+            batch.flush()
+        }
+        PnmEncoding::Ascii => {
+            let mut line_len = 0;
+            for sample in samples {
+                // Up to 3 digits and a separator
+                if line_len + 4 > MAX_LINE_LEN {
+                    sink.write_bytes(b"\n")?;
+                    line_len = 0;
+                }
+                let separator = if line_len == 0 { "" } else { " " };
+                write_sink!(sink, "{separator}{sample}")?;
+                line_len += separator.len() + sample.checked_ilog10().map_or(1, |d| d as usize + 1);
+            }
+            sink.write_bytes(b"\n")
+        }
+    }
+}
+
+/// Writes `image` as a color PPM image with 8 bits per channel, the alpha channel is dropped.
+///
+/// A `Canvas` can be written with `write_ppm(&mut sink, &canvas.surface(), PnmEncoding::Binary)`.
+pub fn write_ppm<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<P, B>,
+    encoding: PnmEncoding,
+) -> Result<(), S::Error> {
+    let magic = match encoding {
+        PnmEncoding::Binary => "P6",
+        PnmEncoding::Ascii => "P3",
+    };
+    let (w, h) = image.size();
+    write_sink!(sink, "{magic}\n{w} {h}\n255\n")?;
+    let samples = image.rows().flatten().flat_map(|p| {
+        let c = p.to_color();
+        [c.r, c.g, c.b]
+    });
+    write_samples(sink, samples, encoding)
+}
+
+/// Reads a binary PPM image (`P6`) with a maximum value of 255, the counterpart of `write_ppm`.
+///
+/// The pixels are returned as opaque colors, other PPM variants return `DecodeError::Unsupported`.
+#[cfg(feature = "alloc")]
+pub fn read_ppm(data: &[u8]) -> Result<Image<Color, Vec<Color>>, DecodeError> {
+    let data = data.strip_prefix(b"P6").ok_or(DecodeError::InvalidMagic)?;
+    let mut fields = [0usize; 3];
+    let mut rest = data;
+    for field in &mut fields {
+        // Whitespace and comments up to the end of their line separate the fields
+        let before = rest.len();
+        loop {
+            match rest.first() {
+                Some(b) if b.is_ascii_whitespace() => rest = &rest[1..],
+                Some(b'#') => {
+                    let end = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+                    rest = &rest[end..];
+                }
+                _ => break,
+            }
+        }
+        if rest.len() == before {
+            return Err(DecodeError::InvalidHeader);
+        }
+        let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+        *field = core::str::from_utf8(&rest[..digits])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or(DecodeError::InvalidHeader)?;
+        rest = &rest[digits..];
+    }
+    let [width, height, max] = fields;
+    if max != 255 {
+        return Err(DecodeError::Unsupported);
+    }
+    // A single whitespace byte ends the header
+    if !rest.first().is_some_and(u8::is_ascii_whitespace) {
+        return Err(DecodeError::InvalidHeader);
+    }
+    let len = width
+        .checked_mul(height)
+        .and_then(|n| n.checked_mul(3))
+        .ok_or(DecodeError::InvalidHeader)?;
+    let samples = rest[1..].get(..len).ok_or(DecodeError::Truncated)?;
+    let colors = samples
+        .chunks_exact(3)
+        .map(|c| Color::rgb(c[0], c[1], c[2]))
+        .collect();
+    Ok(Image::new(colors, width, height))
+}
+
+/// Writes `image` as a grayscale PGM image with 8 bits per pixel, using the luma of every pixel.
+pub fn write_pgm<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<P, B>,
+    encoding: PnmEncoding,
+) -> Result<(), S::Error> {
+    let magic = match encoding {
+        PnmEncoding::Binary => "P5",
+        PnmEncoding::Ascii => "P2",
+    };
+    let (w, h) = image.size();
+    write_sink!(sink, "{magic}\n{w} {h}\n255\n")?;
+    write_samples(
+        sink,
+        image.rows().flatten().map(|p| p.to_color().luma()),
+        encoding,
+    )
+}
+
+/// Writes `image` as a binary PAM image with the `RGB_ALPHA` tuple type, which keeps the alpha channel.
+pub fn write_pam<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<P, B>,
+) -> Result<(), S::Error> {
+    let (w, h) = image.size();
+    write_sink!(
+        sink,
+        "P7\nWIDTH {w}\nHEIGHT {h}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n"
+    )?;
+    let samples = image.rows().flatten().flat_map(|p| {
+        let c = p.to_color();
+        [c.r, c.g, c.b, c.a]
+    });
+    write_samples(sink, samples, PnmEncoding::Binary)
+}
@@ -0,0 +1,216 @@
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{math, Canvas, Color, Draw, PixelFormat};
+
+/// A table of up to 256 colors for indexed-color (`u8`) canvases.
+///
+/// Shapes are drawn onto a `Canvas<u8, _>` with `Index` brushes, and the finished buffer is converted into
+/// any `PixelFormat` in a single pass with `Palette::resolve`. Indices outside of the palette resolve to black.
+///
+/// Like `Canvas`, a `Palette` can either own its colors or borrow them.
+pub struct Palette<B: AsRef<[Color]>> {
+    pub colors: B,
+}
+
+/// Animates a range of palette entries by rotating them over time, the classic "color cycling" effect.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cycle {
+    /// First palette index of the range
+    pub start: u8,
+    /// Last palette index of the range (inclusive)
+    pub end: u8,
+    /// Steps per second, negative rates rotate in the opposite direction
+    pub rate: f32,
+}
+
+impl Cycle {
+    /// Returns the palette index that is displayed at `index` at the given `time` in seconds
+    pub fn remap(&self, index: u8, time: f32) -> u8 {
+        if index < self.start || index > self.end {
+            return index;
+        }
+        let len = (self.end - self.start) as i64 + 1;
+        let offset = math::floor((time * self.rate) as f64) as i64;
+        let i = (index - self.start) as i64 - offset;
+        self.start + i.rem_euclid(len) as u8
+    }
+}
+
+/// Brush that `put`s a palette index onto an indexed canvas.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Index(pub u8);
+
+impl Draw for Index {
+    type T = u8;
+    fn draw(&self, canvas: &mut Canvas<u8, &mut [u8]>, x: i32, y: i32) {
+        canvas.put(x, y, self.0);
+    }
+}
+
+impl<B: AsRef<[Color]>> Palette<B> {
+    /// Creates a new `Palette`, only the first 256 colors can be indexed
+    pub fn new(colors: B) -> Self {
+        Self { colors }
+    }
+
+    /// Returns the number of colors in the palette
+    pub fn len(&self) -> usize {
+        self.colors.as_ref().len()
+    }
+
+    /// Returns `true` if the palette has no colors
+    pub fn is_empty(&self) -> bool {
+        self.colors.as_ref().is_empty()
+    }
+
+    /// Returns the color at `index`, or `None` if the palette is too short
+    pub fn get(&self, index: u8) -> Option<Color> {
+        self.colors.as_ref().get(index as usize).copied()
+    }
+
+    /// Returns the index of the palette color that is closest to `c`.
+    /// The distance is measured in sRGB with the channels weighted by how sensitive the eye is to them.
+    pub fn nearest(&self, c: Color) -> u8 {
+        let distance = |p: &Color| {
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            2 * d(p.r, c.r) + 4 * d(p.g, c.g) + 3 * d(p.b, c.b) + d(p.a, c.a)
+        };
+        self.colors
+            .as_ref()
+            .iter()
+            .take(256)
+            .enumerate()
+            .min_by_key(|(_, p)| distance(p))
+            .map_or(0, |(i, _)| i as u8)
+    }
+
+    /// Returns an `Index` brush for the palette color closest to `c`
+    pub fn brush(&self, c: Color) -> Index {
+        Index(self.nearest(c))
+    }
+
+    /// Builds a lookup table that maps every index to a pixel, applying `cycles` at the given `time` in seconds.
+    pub fn lut<P: PixelFormat>(&self, cycles: &[Cycle], time: f32) -> [P; 256] {
+        let mut lut = [P::from_color(Color::BLACK); 256];
+        for (i, p) in lut.iter_mut().enumerate() {
+            let index = cycles
+                .iter()
+                .fold(i as u8, |index, cycle| cycle.remap(index, time));
+            if let Some(c) = self.get(index) {
+                *p = P::from_color(c);
+            }
+        }
+        lut
+    }
+
+    /// Converts an indexed buffer (usually the `buf` of a `Canvas<u8, _>`) into `out`.
+    /// Only `min(indexed.len(), out.len())` pixels are converted.
+    pub fn resolve<P: PixelFormat>(&self, indexed: &[u8], out: &mut [P]) {
+        self.resolve_cycled(indexed, out, &[], 0.);
+    }
+
+    /// Like `resolve`, but applies palette `cycles` at the given `time` in seconds without modifying the palette.
+    pub fn resolve_cycled<P: PixelFormat>(
+        &self,
+        indexed: &[u8],
+        out: &mut [P],
+        cycles: &[Cycle],
+        time: f32,
+    ) {
+        let lut = self.lut::<P>(cycles, time);
+        for (p, i) in out.iter_mut().zip(indexed) {
+            *p = lut[*i as usize];
+        }
+    }
+}
+
+impl<B: AsRef<[Color]> + AsMut<[Color]>> Palette<B> {
+    /// Rotates the colors in `start..=end` by `steps` in place, which is the same as advancing a `Cycle` by `steps`.
+    pub fn rotate(&mut self, start: u8, end: u8, steps: isize) {
+        let colors = self.colors.as_mut();
+        let end = (end as usize).min(colors.len().saturating_sub(1));
+        if start as usize >= end {
+            return;
+        }
+        let range = &mut colors[start as usize..=end];
+        let steps = steps.rem_euclid(range.len() as isize) as usize;
+        range.rotate_right(steps);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Palette<Vec<Color>> {
+    /// Builds an opaque palette of at most `max_colors` colors for `colors` with median-cut quantization.
+    ///
+    /// The colors are split into boxes until there are `max_colors` boxes, always halving the box with the widest
+    /// channel range at the median of that channel, and every box contributes the average of its colors. If there
+    /// are few enough distinct colors, the palette contains exactly those. Alpha is ignored.
+    pub fn median_cut<I: IntoIterator<Item = Color>>(colors: I, max_colors: usize) -> Self {
+        let mut histogram = BTreeMap::new();
+        for c in colors {
+            *histogram.entry(c.to_u32()).or_insert(0u64) += 1;
+        }
+        let colors: Vec<(Color, u64)> = histogram
+            .into_iter()
+            .map(|(c, n)| (Color::from_u32(c), n))
+            .collect();
+        if colors.len() <= max_colors {
+            return Self::new(colors.into_iter().map(|(c, _)| c).collect());
+        }
+
+        let channel = |c: Color, i: usize| [c.r, c.g, c.b][i];
+        // Returns the channel with the widest range of a box and that range
+        let widest = |colors: &[(Color, u64)]| {
+            (0..3)
+                .map(|i| {
+                    let values = colors.iter().map(|&(c, _)| channel(c, i));
+                    let range = values.clone().max().unwrap() - values.min().unwrap();
+                    (range, i)
+                })
+                .max()
+                .unwrap()
+        };
+        let mut boxes = Vec::from([colors]);
+        while boxes.len() < max_colors {
+            let Some((i, (_, axis))) = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| b.len() > 1)
+                .map(|(i, b)| (i, widest(b)))
+                .max_by_key(|&(_, (range, _))| range)
+            else {
+                break;
+            };
+            let mut b = boxes.swap_remove(i);
+            b.sort_unstable_by_key(|&(c, _)| channel(c, axis));
+            // Split at the median pixel, keeping at least one color on each side
+            let total: u64 = b.iter().map(|&(_, n)| n).sum();
+            let mut count = 0;
+            let median = b
+                .iter()
+                .position(|&(_, n)| {
+                    count += n;
+                    count * 2 >= total
+                })
+                .unwrap()
+                .clamp(0, b.len() - 2);
+            let upper = b.split_off(median + 1);
+            boxes.push(b);
+            boxes.push(upper);
+        }
+
+        let colors = boxes
+            .iter()
+            .map(|b| {
+                let total: u64 = b.iter().map(|&(_, n)| n).sum();
+                let average = |i| {
+                    let sum: u64 = b.iter().map(|&(c, n)| channel(c, i) as u64 * n).sum();
+                    ((sum + total / 2) / total) as u8
+                };
+                Color::rgb(average(0), average(1), average(2))
+            })
+            .collect();
+        Self::new(colors)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::Color;
+
+/// Scales an 8-bit channel down to `bits` bits, rounding to the nearest level
+const fn quantize(c: u8, bits: u32) -> u16 {
+    let max = (1u32 << bits) - 1;
+    ((c as u32 * max + 127) / 255) as u16
+}
+
+/// Scales a channel with `bits` bits back up to 8 bits
+const fn expand(c: u16, bits: u32) -> u8 {
+    let max = (1u32 << bits) - 1;
+    ((c as u32 * 255 + max / 2) / max) as u8
+}
+
+/// Trait for the values stored in a canvas buffer that can be converted from and to a `Color`.
+///
+/// Implementing `PixelFormat` for your own buffer type lets it be used with every part of `framebrush`
+/// that computes colors instead of copying values, like color conversions, gradients and image codecs.
+pub trait PixelFormat: Copy {
+    /// Converts a `Color` into this format, dropping any precision that can't be represented
+    fn from_color(c: Color) -> Self;
+    /// Converts a value of this format back into a `Color`
+    fn to_color(self) -> Color;
+
+    /// Number of bits used for the red, green and blue channels, grayscale formats use the same value for all three.
+    /// This is used by the dithering brushes to scale their thresholds to the distance between two levels.
+    const CHANNEL_BITS: [u8; 3] = [8, 8, 8];
+}
+
+/// Trait for anything that computes a `Color` for each position on the canvas, like flat colors and gradients.
+///
+/// `Shade`s are used by brushes that convert colors into the canvas' pixel format themselves, like the dithering brushes.
+pub trait Shade {
+    fn shade(&self, canvas_x: i32, canvas_y: i32) -> Color;
+}
+
+impl Shade for Color {
+    fn shade(&self, _: i32, _: i32) -> Color {
+        *self
+    }
+}
+
+impl<F: Fn(i32, i32) -> Color> Shade for F {
+    fn shade(&self, canvas_x: i32, canvas_y: i32) -> Color {
+        self(canvas_x, canvas_y)
+    }
+}
+
+/// The 00000000RRRRRRRRGGGGGGGGBBBBBBBB format used by `RGBu32::Rgb`, the upper 8 bits are ignored and
+/// the resulting colors are opaque.
+impl PixelFormat for u32 {
+    fn from_color(c: Color) -> Self {
+        c.to_u32()
+    }
+
+    fn to_color(self) -> Color {
+        Color::from_u32(self)
+    }
+}
+
+impl PixelFormat for Color {
+    fn from_color(c: Color) -> Self {
+        c
+    }
+
+    fn to_color(self) -> Color {
+        self
+    }
+}
+
+/// 16-bit pixel with 5 bits of red, 6 bits of green and 5 bits of blue (RRRRRGGGGGGBBBBB), common on small displays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgb565(pub u16);
+
+impl PixelFormat for Rgb565 {
+    fn from_color(c: Color) -> Self {
+        Self(quantize(c.r, 5) << 11 | quantize(c.g, 6) << 5 | quantize(c.b, 5))
+    }
+
+    fn to_color(self) -> Color {
+        Color::rgb(
+            expand(self.0 >> 11, 5),
+            expand((self.0 >> 5) & 0x3f, 6),
+            expand(self.0 & 0x1f, 5),
+        )
+    }
+
+    const CHANNEL_BITS: [u8; 3] = [5, 6, 5];
+}
+
+/// 8-bit grayscale pixel, colors are converted using their `Color::luma`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Gray8(pub u8);
+
+impl PixelFormat for Gray8 {
+    fn from_color(c: Color) -> Self {
+        Self(c.luma())
+    }
+
+    fn to_color(self) -> Color {
+        Color::rgb(self.0, self.0, self.0)
+    }
+}
+
+/// 4-bit grayscale pixel stored in the low 4 bits, colors are converted using their `Color::luma`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Gray4(pub u8);
+
+impl PixelFormat for Gray4 {
+    fn from_color(c: Color) -> Self {
+        Self(quantize(c.luma(), 4) as u8)
+    }
+
+    fn to_color(self) -> Color {
+        let l = expand(self.0 as u16 & 0xf, 4);
+        Color::rgb(l, l, l)
+    }
+
+    const CHANNEL_BITS: [u8; 3] = [4, 4, 4];
+}
+
+/// 1-bit monochrome pixel, `true` is white. Colors with a `Color::luma` of at least 128 become `true`.
+impl PixelFormat for bool {
+    fn from_color(c: Color) -> Self {
+        c.luma() >= 128
+    }
+
+    fn to_color(self) -> Color {
+        if self {
+            Color::WHITE
+        } else {
+            Color::BLACK
+        }
+    }
+
+    const CHANNEL_BITS: [u8; 3] = [1, 1, 1];
+}
@@ -0,0 +1,518 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{zlib, Color, DecodeError, Image, Palette, PixelFormat, Sink};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Color type of a PNG image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PngColor {
+    /// Luma only
+    Gray,
+    /// Luma and alpha
+    GrayAlpha,
+    /// Red, green and blue, the alpha channel is dropped
+    #[default]
+    Rgb,
+    /// Red, green, blue and alpha
+    Rgba,
+    /// Indices into a palette of up to 256 colors, which can be transparent
+    Indexed,
+}
+
+impl PngColor {
+    fn from_code(code: u8) -> Option<Self> {
+        Some(match code {
+            0 => Self::Gray,
+            2 => Self::Rgb,
+            3 => Self::Indexed,
+            4 => Self::GrayAlpha,
+            6 => Self::Rgba,
+            _ => return None,
+        })
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            Self::Gray => 0,
+            Self::Rgb => 2,
+            Self::Indexed => 3,
+            Self::GrayAlpha => 4,
+            Self::Rgba => 6,
+        }
+    }
+
+    /// Returns the number of samples per pixel
+    fn channels(self) -> usize {
+        match self {
+            Self::Gray | Self::Indexed => 1,
+            Self::GrayAlpha => 2,
+            Self::Rgb => 3,
+            Self::Rgba => 4,
+        }
+    }
+}
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+static CRC_TABLE: [u32; 256] = crc_table();
+
+/// Returns the CRC-32 of a chunk, which covers its type and data
+fn crc32(kind: &[u8], data: &[u8]) -> u32 {
+    let crc = kind.iter().chain(data).fold(!0u32, |crc, &b| {
+        CRC_TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8)
+    });
+    !crc
+}
+
+fn write_chunk<S: Sink>(sink: &mut S, kind: &[u8; 4], data: &[u8]) -> Result<(), S::Error> {
+    sink.write_bytes(&(data.len() as u32).to_be_bytes())?;
+    sink.write_bytes(kind)?;
+    sink.write_bytes(data)?;
+    sink.write_bytes(&crc32(kind, data).to_be_bytes())
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Applies `filter` to `row`, `prev` is the unfiltered row above and `bpp` the number of bytes per pixel
+fn filter_row(filter: u8, row: &[u8], prev: &[u8], bpp: usize, out: &mut Vec<u8>) {
+    out.push(filter);
+    for i in 0..row.len() {
+        let a = if i >= bpp { row[i - bpp] } else { 0 };
+        let c = if i >= bpp { prev[i - bpp] } else { 0 };
+        let predicted = match filter {
+            0 => 0,
+            1 => a,
+            2 => prev[i],
+            3 => ((a as u16 + prev[i] as u16) / 2) as u8,
+            _ => paeth(a, prev[i], c),
+        };
+        out.push(row[i].wrapping_sub(predicted));
+    }
+}
+
+/// Filters and compresses the packed 8-bit samples of an image and writes all chunks
+fn encode<S: Sink>(
+    sink: &mut S,
+    (width, height): (usize, usize),
+    color: PngColor,
+    samples: &[u8],
+    palette: &[Color],
+) -> Result<(), S::Error> {
+    sink.write_bytes(&SIGNATURE)?;
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // 8 bits per sample, compression, filter and interlace methods
+    header.extend_from_slice(&[8, color.code(), 0, 0, 0]);
+    write_chunk(sink, b"IHDR", &header)?;
+
+    if color == PngColor::Indexed {
+        let rgb: Vec<u8> = palette.iter().flat_map(|c| [c.r, c.g, c.b]).collect();
+        write_chunk(sink, b"PLTE", &rgb)?;
+        // Entries after the last transparent one are opaque
+        let alpha: Vec<u8> = palette.iter().map(|c| c.a).collect();
+        let len = alpha.iter().rposition(|&a| a != 255).map_or(0, |i| i + 1);
+        if len > 0 {
+            write_chunk(sink, b"tRNS", &alpha[..len])?;
+        }
+    }
+
+    // Every row uses the filter that gives the smallest sum of absolute differences, a good estimate of which
+    // row compresses best
+    let bpp = color.channels();
+    let stride = width * bpp;
+    let mut filtered = Vec::with_capacity((stride + 1) * height);
+    let mut candidate = Vec::with_capacity(stride + 1);
+    let zero = vec![0; stride];
+    for y in 0..height {
+        let row = &samples[y * stride..][..stride];
+        let prev = if y == 0 {
+            &zero[..]
+        } else {
+            &samples[(y - 1) * stride..][..stride]
+        };
+        let mut best = (u64::MAX, 0);
+        for filter in 0..5 {
+            candidate.clear();
+            filter_row(filter, row, prev, bpp, &mut candidate);
+            let cost = candidate[1..]
+                .iter()
+                .map(|&b| (b as i8).unsigned_abs() as u64)
+                .sum();
+            if cost < best.0 {
+                best = (cost, filter);
+            }
+        }
+        filter_row(best.1, row, prev, bpp, &mut filtered);
+    }
+    write_chunk(sink, b"IDAT", &zlib::compress(&filtered))?;
+    write_chunk(sink, b"IEND", &[])
+}
+
+/// Writes `image` as a PNG file with 8 bits per sample in the given color type.
+///
+/// `PngColor::Indexed` builds a palette from the colors of the image, images with more than 256 colors are written
+/// as `PngColor::Rgba` instead. A `Canvas` can be written with `write_png(&mut sink, &canvas.surface(), color)`.
+pub fn write_png<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<P, B>,
+    color: PngColor,
+) -> Result<(), S::Error> {
+    let colors = || image.rows().flatten().map(|p| p.to_color());
+    let mut palette = Vec::new();
+    let color = if color == PngColor::Indexed {
+        let mut indices = Vec::with_capacity(image.width() * image.height());
+        for c in colors() {
+            let i = match palette.iter().position(|&p| p == c) {
+                Some(i) => i,
+                None if palette.len() < 256 => {
+                    palette.push(c);
+                    palette.len() - 1
+                }
+                None => break,
+            };
+            indices.push(i as u8);
+        }
+        if indices.len() == image.width() * image.height() {
+            return encode(sink, image.size(), color, &indices, &palette);
+        }
+        PngColor::Rgba
+    } else {
+        color
+    };
+
+    let mut samples = Vec::with_capacity(image.width() * image.height() * color.channels());
+    for c in colors() {
+        match color {
+            PngColor::Gray => samples.push(c.luma()),
+            PngColor::GrayAlpha => samples.extend_from_slice(&[c.luma(), c.a]),
+            PngColor::Rgb => samples.extend_from_slice(&[c.r, c.g, c.b]),
+            _ => samples.extend_from_slice(&[c.r, c.g, c.b, c.a]),
+        }
+    }
+    encode(sink, image.size(), color, &samples, &palette)
+}
+
+/// Writes an indexed image, like the buffer of a `Canvas<u8, _>`, as a PNG file with `palette` as its palette.
+///
+/// Indices outside of the palette are black, like with `Palette::resolve`.
+pub fn write_png_indexed<B: AsRef<[u8]>, C: AsRef<[Color]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<u8, B>,
+    palette: &Palette<C>,
+) -> Result<(), S::Error> {
+    let indices: Vec<u8> = image.rows().flatten().copied().collect();
+    let len = indices.iter().max().map_or(0, |&i| i as usize + 1);
+    let colors: Vec<Color> = (0..len.max(1))
+        .map(|i| palette.get(i as u8).unwrap_or(Color::BLACK))
+        .collect();
+    encode(sink, image.size(), PngColor::Indexed, &indices, &colors)
+}
+
+/// A decoded PNG image.
+///
+/// All color types and bit depths are supported, 16-bit samples are reduced to 8 bits. Interlaced images are not
+/// supported.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Png {
+    width: usize,
+    height: usize,
+    color: PngColor,
+    bit_depth: u8,
+    /// Bytes per row of `data`
+    stride: usize,
+    /// Unfiltered rows of samples
+    data: Vec<u8>,
+    /// Palette of indexed images, with the alpha of the `tRNS` chunk
+    palette: Vec<Color>,
+    /// Samples of the transparent color of gray and RGB images
+    transparent: Option<[u16; 3]>,
+}
+
+impl Png {
+    /// Decodes a PNG file, checking the checksums of all chunks
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut rest = data
+            .strip_prefix(&SIGNATURE)
+            .ok_or(DecodeError::InvalidMagic)?;
+        let mut header = None;
+        let mut palette = Vec::new();
+        let mut transparency: &[u8] = &[];
+        let mut compressed = Vec::new();
+        loop {
+            if rest.len() < 12 {
+                return Err(DecodeError::Truncated);
+            }
+            let len = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+            let kind = &rest[4..8];
+            let chunk = rest.get(8..8 + len).ok_or(DecodeError::Truncated)?;
+            let crc = rest.get(8 + len..12 + len).ok_or(DecodeError::Truncated)?;
+            if crc != crc32(kind, chunk).to_be_bytes() {
+                return Err(DecodeError::Corrupt);
+            }
+            rest = &rest[12 + len..];
+
+            match kind {
+                b"IHDR" => header = Some(chunk),
+                _ if header.is_none() => return Err(DecodeError::InvalidHeader),
+                b"PLTE" => {
+                    palette = chunk
+                        .chunks_exact(3)
+                        .map(|c| Color::rgb(c[0], c[1], c[2]))
+                        .collect();
+                }
+                b"tRNS" => transparency = chunk,
+                b"IDAT" => compressed.extend_from_slice(chunk),
+                b"IEND" => break,
+                // Unknown ancillary chunks start with a lowercase letter and can be skipped
+                _ if kind[0].is_ascii_lowercase() => {}
+                _ => return Err(DecodeError::Unsupported),
+            }
+        }
+
+        let header = header.ok_or(DecodeError::InvalidHeader)?;
+        let &[w0, w1, w2, w3, h0, h1, h2, h3, bit_depth, color, compression, filter, interlace] =
+            header
+        else {
+            return Err(DecodeError::InvalidHeader);
+        };
+        let width = u32::from_be_bytes([w0, w1, w2, w3]) as usize;
+        let height = u32::from_be_bytes([h0, h1, h2, h3]) as usize;
+        let color = PngColor::from_code(color).ok_or(DecodeError::InvalidHeader)?;
+        let valid_depth = match color {
+            PngColor::Gray => matches!(bit_depth, 1 | 2 | 4 | 8 | 16),
+            PngColor::Indexed => matches!(bit_depth, 1 | 2 | 4 | 8),
+            _ => matches!(bit_depth, 8 | 16),
+        };
+        if width == 0 || height == 0 || !valid_depth || compression != 0 || filter != 0 {
+            return Err(DecodeError::InvalidHeader);
+        }
+        if interlace != 0 {
+            return Err(DecodeError::Unsupported);
+        }
+        if color == PngColor::Indexed && palette.is_empty() {
+            return Err(DecodeError::InvalidHeader);
+        }
+
+        let mut transparent = None;
+        match color {
+            PngColor::Indexed => {
+                for (c, &a) in palette.iter_mut().zip(transparency) {
+                    c.a = a;
+                }
+            }
+            PngColor::Gray | PngColor::Rgb if !transparency.is_empty() => {
+                let mut samples = [0; 3];
+                for (s, bytes) in samples.iter_mut().zip(transparency.chunks_exact(2)) {
+                    *s = u16::from_be_bytes([bytes[0], bytes[1]]);
+                }
+                transparent = Some(samples);
+            }
+            _ => {}
+        }
+
+        let bits = color.channels() * bit_depth as usize;
+        // The size can't overflow for real images, only for headers that declare an absurd size
+        let stride = width
+            .checked_mul(bits)
+            .ok_or(DecodeError::InvalidHeader)?
+            .div_ceil(8);
+        let len = (stride + 1)
+            .checked_mul(height)
+            .ok_or(DecodeError::InvalidHeader)?;
+        let bpp = bits.div_ceil(8);
+        let mut data = zlib::decompress(&compressed, len)?;
+        if data.len() < len {
+            return Err(DecodeError::Truncated);
+        }
+        // Unfilter in place, every row moves forward by the filter byte of itself and all rows above
+        for y in 0..height {
+            let filter = data[y * (stride + 1)];
+            let src = y * (stride + 1) + 1;
+            let dst = y * stride;
+            for i in 0..stride {
+                let a = if i >= bpp { data[dst + i - bpp] } else { 0 };
+                let b = if y > 0 { data[dst - stride + i] } else { 0 };
+                let c = if y > 0 && i >= bpp {
+                    data[dst - stride + i - bpp]
+                } else {
+                    0
+                };
+                let predicted = match filter {
+                    0 => 0,
+                    1 => a,
+                    2 => b,
+                    3 => ((a as u16 + b as u16) / 2) as u8,
+                    4 => paeth(a, b, c),
+                    _ => return Err(DecodeError::Corrupt),
+                };
+                data[dst + i] = data[src + i].wrapping_add(predicted);
+            }
+        }
+        data.truncate(stride * height);
+
+        Ok(Self {
+            width,
+            height,
+            color,
+            bit_depth,
+            stride,
+            data,
+            palette,
+            transparent,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `(width, height)`
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn color(&self) -> PngColor {
+        self.color
+    }
+
+    /// Returns the number of bits per sample, or per palette index of indexed images
+    pub fn bit_depth(&self) -> u8 {
+        self.bit_depth
+    }
+
+    /// Returns the palette of an indexed image, including the alpha of its entries
+    pub fn palette(&self) -> &[Color] {
+        &self.palette
+    }
+
+    /// Returns the raw value of the `i`th sample of row `y`
+    fn sample(&self, y: usize, i: usize) -> u16 {
+        let depth = self.bit_depth as usize;
+        let row = &self.data[y * self.stride..];
+        match depth {
+            16 => u16::from_be_bytes([row[2 * i], row[2 * i + 1]]),
+            8 => row[i] as u16,
+            _ => {
+                let bit = i * depth;
+                (row[bit / 8] >> (8 - depth - bit % 8)) as u16 & ((1 << depth) - 1)
+            }
+        }
+    }
+
+    /// Scales a sample to 8 bits
+    fn scale(&self, sample: u16) -> u8 {
+        match self.bit_depth {
+            16 => (sample >> 8) as u8,
+            depth => (sample as u32 * 255 / ((1 << depth) - 1)) as u8,
+        }
+    }
+
+    /// Returns the color of the pixel at (`x`, `y`).
+    ///
+    /// Panics if the position is outside of the image.
+    pub fn pixel(&self, x: usize, y: usize) -> Color {
+        assert!(
+            x < self.width && y < self.height,
+            "pixel outside of the image"
+        );
+        let n = self.color.channels();
+        let s = |i: usize| self.sample(y, x * n + i);
+        let opaque = |samples: [u16; 3]| {
+            if self.transparent == Some(samples) {
+                0
+            } else {
+                255
+            }
+        };
+        match self.color {
+            PngColor::Gray => {
+                let g = self.scale(s(0));
+                Color::rgba(g, g, g, opaque([s(0), 0, 0]))
+            }
+            PngColor::GrayAlpha => {
+                let g = self.scale(s(0));
+                Color::rgba(g, g, g, self.scale(s(1)))
+            }
+            PngColor::Rgb => Color::rgba(
+                self.scale(s(0)),
+                self.scale(s(1)),
+                self.scale(s(2)),
+                opaque([s(0), s(1), s(2)]),
+            ),
+            PngColor::Rgba => Color::rgba(
+                self.scale(s(0)),
+                self.scale(s(1)),
+                self.scale(s(2)),
+                self.scale(s(3)),
+            ),
+            PngColor::Indexed => self
+                .palette
+                .get(s(0) as usize)
+                .copied()
+                .unwrap_or(Color::BLACK),
+        }
+    }
+
+    /// Decodes the image into `dst`, a tightly packed buffer of `width * height` pixels like the buffer of a
+    /// `Canvas` with the same size.
+    ///
+    /// Panics if `dst` is too short.
+    pub fn decode_into<P: PixelFormat>(&self, dst: &mut [P]) {
+        assert!(
+            dst.len() >= self.width * self.height,
+            "destination is too short"
+        );
+        for y in 0..self.height {
+            for x in 0..self.width {
+                dst[x + y * self.width] = P::from_color(self.pixel(x, y));
+            }
+        }
+    }
+
+    /// Converts the image into an `Image` of any pixel format
+    pub fn to_image<P: PixelFormat>(&self) -> Image<P, Vec<P>> {
+        let mut data = Vec::with_capacity(self.width * self.height);
+        for y in 0..self.height {
+            data.extend((0..self.width).map(|x| P::from_color(self.pixel(x, y))));
+        }
+        Image::new(data, self.width, self.height)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{BitOrder, Bitmap, Font, FontError, Glyph};
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF1_MODE512: u8 = 0x01;
+const PSF1_MODEHASTAB: u8 = 0x02;
+const PSF1_MODESEQ: u8 = 0x04;
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+const PSF2_HAS_UNICODE_TABLE: u32 = 0x01;
+
+/// Unicode table of a PSF font, mapping glyph indices to the characters they represent
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum UnicodeTable<'a> {
+    /// Little endian UCS-2 values, the glyphs are separated by `0xffff` and sequences are introduced by `0xfffe`
+    Psf1(&'a [u8]),
+    /// UTF-8 strings, the glyphs are separated by `0xff` and sequences are introduced by `0xfe`
+    Psf2(&'a [u8]),
+}
+
+impl UnicodeTable<'_> {
+    /// Returns the index of the first glyph that represents `c` on its own
+    fn find(self, c: char) -> Option<usize> {
+        match self {
+            Self::Psf1(table) => {
+                let (mut glyph, mut in_sequence) = (0, false);
+                for entry in table.chunks_exact(2) {
+                    match u16::from_le_bytes([entry[0], entry[1]]) {
+                        0xffff => (glyph, in_sequence) = (glyph + 1, false),
+                        0xfffe => in_sequence = true,
+                        u if !in_sequence && u as u32 == c as u32 => return Some(glyph),
+                        _ => {}
+                    }
+                }
+                None
+            }
+            Self::Psf2(table) => {
+                let mut utf8 = [0; 4];
+                let needle = c.encode_utf8(&mut utf8).as_bytes();
+                let (mut glyph, mut in_sequence) = (0, false);
+                let mut i = 0;
+                while i < table.len() {
+                    match table[i] {
+                        0xff => (glyph, in_sequence) = (glyph + 1, false),
+                        0xfe => in_sequence = true,
+                        lead => {
+                            // The length of a UTF-8 character is encoded in its first byte
+                            let len = match lead {
+                                0xf0.. => 4,
+                                0xe0.. => 3,
+                                0xc0.. => 2,
+                                _ => 1,
+                            };
+                            if !in_sequence && len == needle.len() && table[i..].starts_with(needle)
+                            {
+                                return Some(glyph);
+                            }
+                            i += len;
+                            continue;
+                        }
+                    }
+                    i += 1;
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Font in the PC Screen Font format (PSF1 or PSF2) used by the Linux console.
+///
+/// The font borrows its data, so it can be loaded from `include_bytes!` without allocating. Fonts with a unicode
+/// table look up glyphs by codepoint, which scans the table on every lookup; fonts without one map codepoints
+/// directly to glyph indices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PsfFont<'a> {
+    glyphs: &'a [u8],
+    glyph_count: usize,
+    glyph_size: (usize, usize),
+    unicode: Option<UnicodeTable<'a>>,
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, FontError> {
+    let bytes = data.get(offset..offset + 4).ok_or(FontError::Truncated)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+impl<'a> PsfFont<'a> {
+    /// Parses a PSF1 or PSF2 font
+    pub fn parse(data: &'a [u8]) -> Result<Self, FontError> {
+        if data.starts_with(&PSF1_MAGIC) {
+            Self::parse_psf1(data)
+        } else if data.starts_with(&PSF2_MAGIC) {
+            Self::parse_psf2(data)
+        } else {
+            Err(FontError::InvalidMagic)
+        }
+    }
+
+    fn parse_psf1(data: &'a [u8]) -> Result<Self, FontError> {
+        let [_, _, mode, height, ..] = *data else {
+            return Err(FontError::Truncated);
+        };
+        let glyph_count = if mode & PSF1_MODE512 != 0 { 512 } else { 256 };
+        let end = 4 + glyph_count * height as usize;
+        let glyphs = data.get(4..end).ok_or(FontError::Truncated)?;
+        let unicode = (mode & (PSF1_MODEHASTAB | PSF1_MODESEQ) != 0)
+            .then(|| UnicodeTable::Psf1(&data[end..]));
+        Ok(Self {
+            glyphs,
+            glyph_count,
+            glyph_size: (8, height as usize),
+            unicode,
+        })
+    }
+
+    fn parse_psf2(data: &'a [u8]) -> Result<Self, FontError> {
+        let header_size = read_u32(data, 8)? as usize;
+        let flags = read_u32(data, 12)?;
+        let glyph_count = read_u32(data, 16)? as usize;
+        let glyph_bytes = read_u32(data, 20)? as usize;
+        let height = read_u32(data, 24)? as usize;
+        let width = read_u32(data, 28)? as usize;
+        if header_size < 32 || glyph_bytes != width.div_ceil(8) * height {
+            return Err(FontError::InvalidHeader);
+        }
+        let end = glyph_count
+            .checked_mul(glyph_bytes)
+            .and_then(|len| len.checked_add(header_size))
+            .ok_or(FontError::InvalidHeader)?;
+        let glyphs = data.get(header_size..end).ok_or(FontError::Truncated)?;
+        let unicode =
+            (flags & PSF2_HAS_UNICODE_TABLE != 0).then(|| UnicodeTable::Psf2(&data[end..]));
+        Ok(Self {
+            glyphs,
+            glyph_count,
+            glyph_size: (width, height),
+            unicode,
+        })
+    }
+
+    /// Returns the number of glyphs
+    pub fn glyph_count(&self) -> usize {
+        self.glyph_count
+    }
+
+    /// Returns the `(width, height)` of every glyph
+    pub fn glyph_size(&self) -> (usize, usize) {
+        self.glyph_size
+    }
+
+    /// Returns `true` if the font maps codepoints to glyphs with a unicode table
+    pub fn has_unicode_table(&self) -> bool {
+        self.unicode.is_some()
+    }
+
+    /// Returns the glyph at `index`, regardless of the character it represents
+    pub fn glyph_at(&self, index: usize) -> Option<Glyph<'a>> {
+        if index >= self.glyph_count {
+            return None;
+        }
+        let (w, h) = self.glyph_size;
+        let len = w.div_ceil(8) * h;
+        let data = &self.glyphs[index * len..(index + 1) * len];
+        Some(Glyph::new(Bitmap::Packed(data, BitOrder::MsbFirst), w, h))
+    }
+
+    /// Returns the index of the glyph that represents `c`
+    pub fn glyph_index(&self, c: char) -> Option<usize> {
+        match self.unicode {
+            Some(table) => table.find(c),
+            None => Some(c as usize),
+        }
+    }
+}
+
+impl Font for PsfFont<'_> {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        self.glyph_at(self.glyph_index(c)?)
+    }
+
+    fn line_height(&self) -> usize {
+        self.glyph_size.1
+    }
+}
@@ -0,0 +1,260 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{sink::Batch, Canvas, Color, DecodeError, Image, PixelFormat, Sink};
+
+const MAGIC: &[u8; 4] = b"qoif";
+const HEADER_LEN: usize = 14;
+const END_MARKER: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 1];
+
+const OP_INDEX: u8 = 0x00;
+const OP_DIFF: u8 = 0x40;
+const OP_LUMA: u8 = 0x80;
+const OP_RUN: u8 = 0xc0;
+const OP_RGB: u8 = 0xfe;
+const OP_RGBA: u8 = 0xff;
+const MASK: u8 = 0xc0;
+/// Longest run of a single `OP_RUN`, the two larger values are the `OP_RGB` and `OP_RGBA` tags
+const MAX_RUN: u8 = 62;
+
+fn hash(c: Color) -> usize {
+    (c.r as usize * 3 + c.g as usize * 5 + c.b as usize * 7 + c.a as usize * 11) % 64
+}
+
+/// Options of `write_qoi`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QoiOptions {
+    /// Keeps the alpha channel, otherwise all pixels are written as opaque
+    pub alpha: bool,
+    /// Marks all channels as linear instead of sRGB with linear alpha, this is only informative
+    pub linear: bool,
+}
+
+/// Writes `image` as a QOI image.
+///
+/// Encoding doesn't allocate, a `Canvas` can be written with `write_qoi(&mut sink, &canvas.surface(), options)`.
+pub fn write_qoi<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<P, B>,
+    options: QoiOptions,
+) -> Result<(), S::Error> {
+    let (w, h) = image.size();
+    let mut out = Batch::new(sink);
+    out.extend(MAGIC)?;
+    out.extend(&(w as u32).to_be_bytes())?;
+    out.extend(&(h as u32).to_be_bytes())?;
+    out.extend(&[if options.alpha { 4 } else { 3 }, options.linear as u8])?;
+
+    let mut index = [Color::rgba(0, 0, 0, 0); 64];
+    let mut prev = Color::BLACK;
+    let mut run = 0;
+    for p in image.rows().flatten() {
+        let mut c = p.to_color();
+        if !options.alpha {
+            c.a = 255;
+        }
+        if c == prev {
+            run += 1;
+            if run == MAX_RUN {
+                out.push(OP_RUN | (run - 1))?;
+                run = 0;
+            }
+            continue;
+        }
+        if run > 0 {
+            out.push(OP_RUN | (run - 1))?;
+            run = 0;
+        }
+
+        let i = hash(c);
+        if index[i] == c {
+            out.push(OP_INDEX | i as u8)?;
+        } else if c.a != prev.a {
+            out.extend(&[OP_RGBA, c.r, c.g, c.b, c.a])?;
+        } else {
+            let dr = c.r.wrapping_sub(prev.r) as i8;
+            let dg = c.g.wrapping_sub(prev.g) as i8;
+            let db = c.b.wrapping_sub(prev.b) as i8;
+            let (dr_dg, db_dg) = (dr.wrapping_sub(dg), db.wrapping_sub(dg));
+            if (-2..2).contains(&dr) && (-2..2).contains(&dg) && (-2..2).contains(&db) {
+                let (dr, dg, db) = ((dr + 2) as u8, (dg + 2) as u8, (db + 2) as u8);
+                out.push(OP_DIFF | dr << 4 | dg << 2 | db)?;
+            } else if (-32..32).contains(&dg)
+                && (-8..8).contains(&dr_dg)
+                && (-8..8).contains(&db_dg)
+            {
+                out.extend(&[
+                    OP_LUMA | (dg + 32) as u8,
+                    ((dr_dg + 8) as u8) << 4 | (db_dg + 8) as u8,
+                ])?;
+            } else {
+                out.extend(&[OP_RGB, c.r, c.g, c.b])?;
+            }
+        }
+        index[i] = c;
+        prev = c;
+    }
+    if run > 0 {
+        out.push(OP_RUN | (run - 1))?;
+    }
+    out.extend(&END_MARKER)?;
+    out.flush()
+}
+
+/// A QOI image that is decoded while it is read, without allocating.
+///
+/// The pixels can be streamed to a closure with `decode_with`, or decompressed straight into a buffer or a region
+/// of a `Canvas`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Qoi<'a> {
+    data: &'a [u8],
+    width: usize,
+    height: usize,
+    alpha: bool,
+    linear: bool,
+}
+
+impl<'a> Qoi<'a> {
+    /// Parses the header of a QOI image, the pixels are only checked while decoding.
+    /// Headers that declare more pixels than the rest of the data could possibly encode are rejected as truncated.
+    pub fn parse(data: &'a [u8]) -> Result<Self, DecodeError> {
+        if !data.starts_with(MAGIC) {
+            return Err(DecodeError::InvalidMagic);
+        }
+        let header = data.get(..HEADER_LEN).ok_or(DecodeError::Truncated)?;
+        let width = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let height = u32::from_be_bytes([header[8], header[9], header[10], header[11]]) as usize;
+        let alpha = match header[12] {
+            3 => false,
+            4 => true,
+            _ => return Err(DecodeError::InvalidHeader),
+        };
+        let linear = match header[13] {
+            0 => false,
+            1 => true,
+            _ => return Err(DecodeError::InvalidHeader),
+        };
+        let pixels = width
+            .checked_mul(height)
+            .ok_or(DecodeError::InvalidHeader)?;
+        // No byte encodes more pixels than a full run
+        if pixels > (data.len() - HEADER_LEN).saturating_mul(MAX_RUN as usize) {
+            return Err(DecodeError::Truncated);
+        }
+        Ok(Self {
+            data: &data[HEADER_LEN..],
+            width,
+            height,
+            alpha,
+            linear,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns `(width, height)`
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// Returns `true` if the header declares an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        self.alpha
+    }
+
+    /// Returns `true` if the header declares all channels as linear instead of sRGB
+    pub fn is_linear(&self) -> bool {
+        self.linear
+    }
+
+    /// Decodes the pixels in row order and calls `f` with the position and color of every pixel.
+    ///
+    /// If the data is truncated or corrupt, the pixels before the error have already been passed to `f`.
+    pub fn decode_with<F: FnMut(usize, usize, Color)>(&self, mut f: F) -> Result<(), DecodeError> {
+        let mut index = [Color::rgba(0, 0, 0, 0); 64];
+        let mut c = Color::BLACK;
+        let mut pos = 0;
+        let mut next = || -> Result<u8, DecodeError> {
+            let byte = *self.data.get(pos).ok_or(DecodeError::Truncated)?;
+            pos += 1;
+            Ok(byte)
+        };
+        let mut run = 0;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if run > 0 {
+                    run -= 1;
+                    f(x, y, c);
+                    continue;
+                }
+                let op = next()?;
+                match op {
+                    OP_RGB => (c.r, c.g, c.b) = (next()?, next()?, next()?),
+                    OP_RGBA => (c.r, c.g, c.b, c.a) = (next()?, next()?, next()?, next()?),
+                    _ => match op & MASK {
+                        OP_INDEX => c = index[op as usize],
+                        OP_DIFF => {
+                            c.r = c.r.wrapping_add((op >> 4 & 3).wrapping_sub(2));
+                            c.g = c.g.wrapping_add((op >> 2 & 3).wrapping_sub(2));
+                            c.b = c.b.wrapping_add((op & 3).wrapping_sub(2));
+                        }
+                        OP_LUMA => {
+                            let dg = (op & 0x3f).wrapping_sub(32);
+                            let byte = next()?;
+                            c.r = c.r.wrapping_add(dg.wrapping_add(byte >> 4).wrapping_sub(8));
+                            c.g = c.g.wrapping_add(dg);
+                            c.b =
+                                c.b.wrapping_add(dg.wrapping_add(byte & 0x0f).wrapping_sub(8));
+                        }
+                        _ => run = op & 0x3f,
+                    },
+                }
+                index[hash(c)] = c;
+                f(x, y, c);
+            }
+        }
+        if run > 0 {
+            return Err(DecodeError::Corrupt);
+        }
+        Ok(())
+    }
+
+    /// Decodes the image into `dst`, a tightly packed buffer of `width * height` pixels like the buffer of a
+    /// `Canvas` with the same size.
+    ///
+    /// Panics if `dst` is too short.
+    pub fn decode_into<P: PixelFormat>(&self, dst: &mut [P]) -> Result<(), DecodeError> {
+        assert!(
+            dst.len() >= self.width * self.height,
+            "destination is too short"
+        );
+        self.decode_with(|x, y, c| dst[x + y * self.width] = P::from_color(c))
+    }
+
+    /// Decodes the image onto `canvas` with its top-left corner at (`x`, `y`) in canvas coordinates, like drawing an
+    /// `Image` there, but without decompressing it first.
+    pub fn decode_to_canvas<P: PixelFormat, B: AsMut<[P]> + AsRef<[P]>>(
+        &self,
+        canvas: &mut Canvas<P, B>,
+        x: i32,
+        y: i32,
+    ) -> Result<(), DecodeError> {
+        self.decode_with(|px, py, c| {
+            canvas.put(x + px as i32, y + py as i32, P::from_color(c));
+        })
+    }
+
+    /// Decodes the image into a new `Image`
+    #[cfg(feature = "alloc")]
+    pub fn to_image<P: PixelFormat>(&self) -> Result<Image<P, Vec<P>>, DecodeError> {
+        let mut data = Vec::with_capacity(self.width * self.height);
+        self.decode_with(|_, _, c| data.push(P::from_color(c)))?;
+        Ok(Image::new(data, self.width, self.height))
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{math, Canvas, Color, Image, PixelFormat};
+
+/// Filter used to compute the pixels of a resized image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Filter {
+    /// Picks the closest source pixel, this is what `Canvas::put` does when scaling
+    #[default]
+    Nearest,
+    /// Interpolates between the 2x2 closest source pixels
+    Bilinear,
+    /// Interpolates between the 4x4 closest source pixels with a Catmull-Rom spline, sharper than `Bilinear`
+    Bicubic,
+    /// Averages every source pixel covered by the destination pixel, weighted by the covered area.
+    /// This is the filter to use when downscaling, the others skip source pixels and alias.
+    Area,
+}
+
+fn catmull_rom(x: f32) -> f32 {
+    let x = x.abs();
+    if x < 1. {
+        1.5 * x * x * x - 2.5 * x * x + 1.
+    } else if x < 2. {
+        -0.5 * x * x * x + 2.5 * x * x - 4. * x + 2.
+    } else {
+        0.
+    }
+}
+
+impl Filter {
+    /// Calls `f` with the index and weight of every source pixel that contributes to destination pixel `d`
+    /// along one axis. The weights are not normalized.
+    fn taps<F: FnMut(usize, f32)>(self, d: usize, src_len: usize, dst_len: usize, mut f: F) {
+        let scale = src_len as f32 / dst_len as f32;
+        let clamp = |i: i64| i.clamp(0, src_len as i64 - 1) as usize;
+        match self {
+            Self::Nearest => f(clamp(((d as f32 + 0.5) * scale) as i64), 1.),
+            Self::Bilinear => {
+                let s = (d as f32 + 0.5) * scale - 0.5;
+                let i = math::floor(s as f64) as i64;
+                let t = s - i as f32;
+                f(clamp(i), 1. - t);
+                f(clamp(i + 1), t);
+            }
+            Self::Bicubic => {
+                let s = (d as f32 + 0.5) * scale - 0.5;
+                let i = math::floor(s as f64) as i64;
+                let t = s - i as f32;
+                for k in -1..=2 {
+                    f(clamp(i + k), catmull_rom(t - k as f32));
+                }
+            }
+            Self::Area => {
+                let start = d as f32 * scale;
+                let end = (d + 1) as f32 * scale;
+                let mut i = start as usize;
+                while (i as f32) < end && i < src_len {
+                    let covered = (end.min(i as f32 + 1.) - start.max(i as f32)).max(0.);
+                    f(i, covered);
+                    i += 1;
+                }
+            }
+        }
+    }
+}
+
+impl<T: PixelFormat, B: AsRef<[T]>> Image<T, B> {
+    /// Computes the pixel at (`x`, `y`) of the image resized to `dst_size` with the given `filter`.
+    pub fn sample(&self, x: usize, y: usize, dst_size: (usize, usize), filter: Filter) -> T {
+        let mut sum = [0f32; 4];
+        let mut total = 0.;
+        filter.taps(y, self.height(), dst_size.1, |sy, wy| {
+            filter.taps(x, self.width(), dst_size.0, |sx, wx| {
+                let w = wx * wy;
+                if let Some(p) = self.get(sx, sy) {
+                    let c = p.to_color();
+                    for (s, c) in sum.iter_mut().zip([c.r, c.g, c.b, c.a]) {
+                        *s += c as f32 * w;
+                    }
+                    total += w;
+                }
+            });
+        });
+        let channel = |s: f32| {
+            if total == 0. {
+                0
+            } else {
+                math::round((s / total).clamp(0., 255.) as f64) as u8
+            }
+        };
+        T::from_color(Color::rgba(
+            channel(sum[0]),
+            channel(sum[1]),
+            channel(sum[2]),
+            channel(sum[3]),
+        ))
+    }
+
+    /// Resizes the image into `dst`, a tightly packed buffer of `dst_size`. Nothing is written if the image or
+    /// `dst_size` is empty.
+    pub fn resample_into(&self, dst: &mut [T], dst_size: (usize, usize), filter: Filter) {
+        if self.is_empty() || dst_size.0 == 0 || dst_size.1 == 0 {
+            return;
+        }
+        for (y, row) in dst.chunks_mut(dst_size.0).take(dst_size.1).enumerate() {
+            for (x, p) in row.iter_mut().enumerate() {
+                *p = self.sample(x, y, dst_size, filter);
+            }
+        }
+    }
+
+    /// Returns a copy of the image resized to `w`x`h` with the given `filter`.
+    /// Resizing an empty image, or resizing to an empty size, returns an empty image.
+    #[cfg(feature = "alloc")]
+    pub fn resampled(&self, w: usize, h: usize, filter: Filter) -> Image<T, Vec<T>> {
+        if self.is_empty() || w == 0 || h == 0 {
+            return Image::new(Vec::new(), 0, 0);
+        }
+        let mut data = Vec::with_capacity(w * h);
+        for y in 0..h {
+            for x in 0..w {
+                data.push(self.sample(x, y, (w, h), filter));
+            }
+        }
+        Image::new(data, w, h)
+    }
+}
+
+impl<T: PixelFormat, B: AsMut<[T]> + AsRef<[T]>> Canvas<T, B> {
+    /// Resizes `src` onto the whole surface with the given `filter`.
+    ///
+    /// This is an alternative to drawing straight onto a scaled `Canvas`: draw onto a canvas whose surface has the same
+    /// size as the canvas, then `present` its buffer onto the real surface. Unlike `put`, this can smooth the result
+    /// when upscaling and average the pixels when the surface is smaller than the canvas.
+    pub fn present<S: AsRef<[T]>>(&mut self, src: &Image<T, S>, filter: Filter) {
+        src.resample_into(self.buf.as_mut(), self.surface_size, filter);
+    }
+}
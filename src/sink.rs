@@ -0,0 +1,192 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Destination for the bytes produced by the image encoders, like `write_ppm`.
+///
+/// This is a minimal `no_std` replacement for `std::io::Write`: encoders write to any `Sink`, which can be a
+/// `SliceSink` over a fixed buffer, a `Vec<u8>` with the `alloc` feature, any `fmt::Write` through `FmtSink` (for
+/// text formats) or any `std::io::Write` through `IoSink` with the `std` feature.
+pub trait Sink {
+    type Error;
+
+    /// Writes all of `bytes` or returns an error
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl<S: Sink + ?Sized> Sink for &mut S {
+    type Error = S::Error;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        (**self).write_bytes(bytes)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Sink for Vec<u8> {
+    type Error = core::convert::Infallible;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Error returned by `SliceSink` when its buffer is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferFull;
+
+impl fmt::Display for BufferFull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sink buffer is full")
+    }
+}
+
+/// `Sink` that fills a borrowed byte buffer from the start.
+#[derive(Debug)]
+pub struct SliceSink<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceSink<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Returns the number of bytes written so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing was written yet
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bytes written so far
+    pub fn written(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl Sink for SliceSink<'_> {
+    type Error = BufferFull;
+
+    /// Writes all of `bytes`, or nothing if they don't fit into the rest of the buffer
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), BufferFull> {
+        let dst = self
+            .buf
+            .get_mut(self.len..self.len + bytes.len())
+            .ok_or(BufferFull)?;
+        dst.copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+}
+
+/// `Sink` that forwards to a `fmt::Write`, like a `String` or a `fmt::Formatter`.
+/// Only text formats can be written to it, bytes that aren't valid UTF-8 return an error.
+#[derive(Debug)]
+pub struct FmtSink<W: fmt::Write>(pub W);
+
+impl<W: fmt::Write> Sink for FmtSink<W> {
+    type Error = fmt::Error;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), fmt::Error> {
+        let text = core::str::from_utf8(bytes).map_err(|_| fmt::Error)?;
+        self.0.write_str(text)
+    }
+}
+
+/// `Sink` that forwards to a `std::io::Write`, like a `File` or `Stdout`.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct IoSink<W: std::io::Write>(pub W);
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Sink for IoSink<W> {
+    type Error = std::io::Error;
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), std::io::Error> {
+        self.0.write_all(bytes)
+    }
+}
+
+/// Collects bytes into a small buffer and writes them to the sink in batches, so encoders that produce one byte at a
+/// time don't call the sink for every byte.
+pub(crate) struct Batch<'s, S: Sink> {
+    sink: &'s mut S,
+    buf: [u8; 256],
+    len: usize,
+}
+
+impl<'s, S: Sink> Batch<'s, S> {
+    pub(crate) fn new(sink: &'s mut S) -> Self {
+        Self {
+            sink,
+            buf: [0; 256],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, byte: u8) -> Result<(), S::Error> {
+        self.buf[self.len] = byte;
+        self.len += 1;
+        if self.len == self.buf.len() {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn extend(&mut self, bytes: &[u8]) -> Result<(), S::Error> {
+        bytes.iter().try_for_each(|&b| self.push(b))
+    }
+
+    /// Writes the buffered bytes, this has to be called after the last `push`
+    pub(crate) fn flush(&mut self) -> Result<(), S::Error> {
+        let len = core::mem::take(&mut self.len);
+        self.sink.write_bytes(&self.buf[..len])
+    }
+}
+
+/// Adapter that lets `write!` format numbers and headers straight into a `Sink`.
+///
+/// `fmt::Write` can't carry the sink's error, so it is stored and returned by `finish`, see `write_sink!`.
+pub(crate) struct Formatter<'s, S: Sink> {
+    sink: &'s mut S,
+    error: Option<S::Error>,
+}
+
+impl<'s, S: Sink> Formatter<'s, S> {
+    pub(crate) fn new(sink: &'s mut S) -> Self {
+        Self { sink, error: None }
+    }
+
+    /// Returns the error of the sink if writing the formatted text failed.
+    /// Formatting numbers and strings never fails on its own.
+    pub(crate) fn finish(self) -> Result<(), S::Error> {
+        self.error.map_or(Ok(()), Err)
+    }
+}
+
+impl<S: Sink> fmt::Write for Formatter<'_, S> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.sink.write_bytes(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+/// Writes formatted text into a `Sink`, returning the sink's error
+macro_rules! write_sink {
+    ($sink:expr, $($arg:tt)*) => {{
+        let mut formatter = $crate::sink::Formatter::new($sink);
+        let _ = core::fmt::Write::write_fmt(&mut formatter, format_args!($($arg)*));
+        formatter.finish()
+    }};
+}
+
+pub(crate) use write_sink;
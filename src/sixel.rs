@@ -0,0 +1,150 @@
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use crate::PixelFormat;
+use crate::{
+    sink::{write_sink, Batch},
+    Color, Image, Palette, Sink,
+};
+
+/// Sixel characters encode 6 vertically stacked pixels as 0x3f plus a bit per pixel, the top one is the lowest bit
+const SIXEL_BASE: u8 = 0x3f;
+/// A run of the same sixel is only shorter as a repeat introducer (`!count sixel`) from this length on
+const MIN_REPEAT: usize = 4;
+
+/// Writes `n` as decimal digits
+fn push_decimal<S: Sink>(out: &mut Batch<S>, n: usize) -> Result<(), S::Error> {
+    if n >= 10 {
+        push_decimal(out, n / 10)?;
+    }
+    out.push(b'0' + (n % 10) as u8)
+}
+
+/// Writes a run of `count` identical sixels
+fn write_run<S: Sink>(out: &mut Batch<S>, sixel: u8, count: usize) -> Result<(), S::Error> {
+    if count >= MIN_REPEAT {
+        out.push(b'!')?;
+        push_decimal(out, count)?;
+        out.push(sixel)
+    } else {
+        (0..count).try_for_each(|_| out.push(sixel))
+    }
+}
+
+/// Writes the color registers and the pixels of an image of palette indices, `None` pixels are left transparent
+fn write_sixels<S: Sink, F: Fn(usize, usize) -> Option<u8>>(
+    sink: &mut S,
+    (w, h): (usize, usize),
+    colors: &[Color],
+    index: F,
+) -> Result<(), S::Error> {
+    // The second parameter keeps pixels without a color transparent, the raster attributes declare a 1:1 pixel
+    // aspect ratio and the size
+    write_sink!(sink, "\x1bP0;1;0q\"1;1;{w};{h}")?;
+    for (i, c) in colors.iter().enumerate() {
+        // Color registers are set in percent
+        let [r, g, b] = [c.r, c.g, c.b].map(|v| (v as u32 * 100 + 127) / 255);
+        write_sink!(sink, "#{i};2;{r};{g};{b}")?;
+    }
+
+    let mut out = Batch::new(sink);
+    for band in 0..h.div_ceil(6) {
+        let rows = band * 6..(band * 6 + 6).min(h);
+        let mut used = [false; 256];
+        for y in rows.clone() {
+            for x in 0..w {
+                if let Some(i) = index(x, y) {
+                    used[i as usize] = true;
+                }
+            }
+        }
+
+        // Every color is drawn over the band from the left, separated by carriage returns
+        let mut first = true;
+        for color in (0..256).filter(|&i| used[i]) {
+            if !first {
+                out.push(b'$')?;
+            }
+            first = false;
+            out.push(b'#')?;
+            push_decimal(&mut out, color)?;
+
+            let (mut run, mut count) = (0, 0);
+            for x in 0..w {
+                let bits = rows
+                    .clone()
+                    .enumerate()
+                    .filter(|&(_, y)| index(x, y) == Some(color as u8))
+                    .fold(0, |bits, (dy, _)| bits | 1 << dy);
+                let sixel = SIXEL_BASE + bits;
+                if count > 0 && sixel != run {
+                    write_run(&mut out, run, count)?;
+                    count = 0;
+                }
+                run = sixel;
+                count += 1;
+            }
+            // Blank sixels at the end of the band don't have to be drawn
+            if run != SIXEL_BASE {
+                write_run(&mut out, run, count)?;
+            }
+        }
+        if rows.end < h {
+            out.push(b'-')?;
+        }
+    }
+    out.extend(b"\x1b\\")?;
+    out.flush()
+}
+
+/// Writes `image` as a Sixel image, a DCS escape sequence that terminals like xterm, foot, WezTerm and mlterm
+/// display inline.
+///
+/// The palette is built with median-cut quantization (`Palette::median_cut`) and has at most `max_colors` colors,
+/// up to the 256 color registers most terminals support. Pixels with an alpha below 128 are left transparent.
+/// A `Canvas` can be written with `write_sixel(&mut sink, &canvas.surface(), 256)`.
+#[cfg(feature = "alloc")]
+pub fn write_sixel<P: PixelFormat, B: AsRef<[P]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<P, B>,
+    max_colors: usize,
+) -> Result<(), S::Error> {
+    let (w, h) = image.size();
+    let colors: Vec<Option<Color>> = image
+        .rows()
+        .flatten()
+        .map(|p| Some(p.to_color()).filter(|c| c.a >= 128))
+        .collect();
+    let palette = Palette::median_cut(colors.iter().flatten().copied(), max_colors.clamp(1, 256));
+    let mut cache = BTreeMap::new();
+    let indices: Vec<Option<u8>> = colors
+        .iter()
+        .map(|c| {
+            c.map(|c| {
+                *cache
+                    .entry(c.to_u32())
+                    .or_insert_with(|| palette.nearest(c.with_alpha(255)))
+            })
+        })
+        .collect();
+    write_sixels(sink, (w, h), &palette.colors, |x, y| indices[x + y * w])
+}
+
+/// Writes an indexed image, like the buffer of a `Canvas<u8, _>`, as a Sixel image with the first 256 colors of
+/// `palette` as its color registers.
+///
+/// Indices outside of the palette are left transparent. This doesn't allocate.
+pub fn write_sixel_indexed<B: AsRef<[u8]>, C: AsRef<[Color]>, S: Sink>(
+    sink: &mut S,
+    image: &Image<u8, B>,
+    palette: &Palette<C>,
+) -> Result<(), S::Error> {
+    let len = palette.len().min(256);
+    write_sixels(
+        sink,
+        image.size(),
+        &palette.colors.as_ref()[..len],
+        |x, y| image.get(x, y).copied().filter(|&i| (i as usize) < len),
+    )
+}
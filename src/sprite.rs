@@ -0,0 +1,220 @@
+use core::marker::PhantomData;
+
+use crate::{BlendSpace, Canvas, Color, Draw, Image, PixelFormat};
+
+/// Which pixels of a `Sprite` are left out when it is drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Transparency<T> {
+    /// Every pixel is drawn
+    #[default]
+    Opaque,
+    /// Pixels equal to the color key are skipped
+    Key(T),
+    /// Pixels are blended over the canvas using the alpha of `PixelFormat::to_color` in the given space,
+    /// fully transparent pixels are skipped. Only formats with an alpha channel, like `Color`, are ever translucent.
+    Alpha(BlendSpace),
+}
+
+/// `draw`able image region with transparency, so only the shape of the sprite overwrites the background.
+///
+/// The sprite is drawn onto canvases of pixel format `T`, by default the format of its image. Sprites of another
+/// format are converted through `Color`, e.g. a sprite of `Color` pixels with `Transparency::Alpha` blends its alpha
+/// over a `u32` canvas.
+#[derive(Clone, Debug)]
+pub struct Sprite<'a, S, T = S> {
+    pub image: Image<S, &'a [S]>,
+    pub transparency: Transparency<S>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, S, T> Sprite<'a, S, T> {
+    /// Creates an opaque sprite
+    pub fn new(image: Image<S, &'a [S]>) -> Self {
+        Self {
+            image,
+            transparency: Transparency::Opaque,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the sprite with its `Transparency` replaced
+    pub fn with_transparency(self, transparency: Transparency<S>) -> Self {
+        Self {
+            transparency,
+            ..self
+        }
+    }
+}
+
+impl<S: PixelFormat + PartialEq, T: PixelFormat> Draw for Sprite<'_, S, T> {
+    type T = T;
+    fn draw(&self, canvas: &mut Canvas<T, &mut [T]>, start_x: i32, start_y: i32) {
+        for (y, row) in self.image.rows().enumerate() {
+            for (x, p) in row.enumerate() {
+                let (x, y) = (start_x + x as i32, start_y + y as i32);
+                let src = p.to_color();
+                match self.transparency {
+                    Transparency::Opaque => canvas.put(x, y, T::from_color(src)),
+                    Transparency::Key(key) => {
+                        if *p != key {
+                            canvas.put(x, y, T::from_color(src));
+                        }
+                    }
+                    Transparency::Alpha(space) => match src.a {
+                        0 => {}
+                        255 => canvas.put(x, y, T::from_color(src)),
+                        a => canvas.put_with(x, y, |dst| {
+                            let dst = dst.to_color();
+                            T::from_color(Color::rgba(
+                                space.mix(dst.r, src.r, a),
+                                space.mix(dst.g, src.g, a),
+                                space.mix(dst.b, src.b, a),
+                                dst.a.max(a),
+                            ))
+                        }),
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// An image that is sliced into equally sized frames, read left to right and top to bottom.
+#[derive(Clone, Debug)]
+pub struct SpriteSheet<'a, T> {
+    pub image: Image<T, &'a [T]>,
+    pub frame_size: (usize, usize),
+    /// Duration of every frame in seconds. If there are fewer durations than frames, the last duration is used for
+    /// the remaining frames.
+    pub durations: &'a [f32],
+    /// The `Transparency` of the frame sprites
+    pub transparency: Transparency<T>,
+}
+
+impl<'a, T: Copy> SpriteSheet<'a, T> {
+    /// Creates a sheet of opaque frames
+    pub fn new(image: Image<T, &'a [T]>, frame_size: (usize, usize), durations: &'a [f32]) -> Self {
+        Self {
+            image,
+            frame_size,
+            durations,
+            transparency: Transparency::Opaque,
+        }
+    }
+
+    /// Returns the sheet with the `Transparency` of its frames replaced
+    pub fn with_transparency(self, transparency: Transparency<T>) -> Self {
+        Self {
+            transparency,
+            ..self
+        }
+    }
+
+    /// Returns the number of frames per row
+    pub fn columns(&self) -> usize {
+        self.image
+            .width()
+            .checked_div(self.frame_size.0)
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of frames
+    pub fn len(&self) -> usize {
+        let rows = self
+            .image
+            .height()
+            .checked_div(self.frame_size.1)
+            .unwrap_or(0);
+        self.columns() * rows
+    }
+
+    /// Returns `true` if the sheet has no frames
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the frame at `index`, or `None` if there is no such frame
+    pub fn frame(&self, index: usize) -> Option<Sprite<'a, T>> {
+        if index >= self.len() {
+            return None;
+        }
+        let (w, h) = self.frame_size;
+        let (col, row) = (index % self.columns(), index / self.columns());
+        let image = self.image.clone().into_region(col * w, row * h, w, h);
+        Some(Sprite {
+            image,
+            transparency: self.transparency,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the duration of the frame at `index` in seconds
+    pub fn duration(&self, index: usize) -> f32 {
+        self.durations
+            .get(index)
+            .or(self.durations.last())
+            .copied()
+            .unwrap_or(0.)
+    }
+
+    /// Returns the duration of the whole animation in seconds
+    pub fn total_duration(&self) -> f32 {
+        (0..self.len()).map(|i| self.duration(i)).sum()
+    }
+
+    /// Returns the index of the frame that is shown `time` seconds after the animation started.
+    /// Looping animations start over after the last frame, other animations stay on the last frame.
+    pub fn frame_index_at(&self, time: f32, looping: bool) -> usize {
+        let total = self.total_duration();
+        if self.is_empty() || total <= 0. {
+            return 0;
+        }
+        let mut time = time.max(0.);
+        if looping {
+            time -= (time / total) as u32 as f32 * total;
+        }
+        for i in 0..self.len() {
+            time -= self.duration(i);
+            if time < 0. {
+                return i;
+            }
+        }
+        self.len() - 1
+    }
+}
+
+/// `draw`able animation that shows the frame of a `SpriteSheet` at a given time.
+///
+/// Like `Sprite`, the frames are drawn onto canvases of pixel format `T`, by default the format of the sheet.
+#[derive(Clone, Debug)]
+pub struct AnimatedSprite<'a, S, T = S> {
+    pub sheet: &'a SpriteSheet<'a, S>,
+    /// Seconds since the animation started
+    pub time: f32,
+    pub looping: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, S, T> AnimatedSprite<'a, S, T> {
+    /// Creates an animation that shows the frame at `time` seconds
+    pub fn new(sheet: &'a SpriteSheet<'a, S>, time: f32, looping: bool) -> Self {
+        Self {
+            sheet,
+            time,
+            looping,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S: PixelFormat + PartialEq, T: PixelFormat> Draw for AnimatedSprite<'_, S, T> {
+    type T = T;
+    fn draw(&self, canvas: &mut Canvas<T, &mut [T]>, x: i32, y: i32) {
+        let index = self.sheet.frame_index_at(self.time, self.looping);
+        if let Some(frame) = self.sheet.frame(index) {
+            Sprite::<S, T>::new(frame.image)
+                .with_transparency(frame.transparency)
+                .draw(canvas, x, y);
+        }
+    }
+}
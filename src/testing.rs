@@ -0,0 +1,233 @@
+//! Golden-image snapshot testing for code that renders with `framebrush`.
+//!
+//! A test renders into an in-memory canvas with `render` and compares the result to a reference image stored next
+//! to the tests with `Snapshot::assert`. References are PNG or binary PPM files, picked by the extension of their
+//! name. When a comparison fails, a `.diff.png` that marks the mismatched pixels in red and an `.actual.png` with the
+//! rendered image are written next to the reference.
+//!
+//! Running the tests with the environment variable `FRAMEBRUSH_BLESS=1` writes the rendered images as the new
+//! references instead of comparing them.
+
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    read_ppm, write_png, write_ppm, Canvas, Color, DecodeError, Image, IoSink, PixelFormat,
+    PngColor, PnmEncoding,
+};
+
+/// Environment variable that turns on blessing mode
+pub const BLESS_VAR: &str = "FRAMEBRUSH_BLESS";
+
+/// Renders into a new `u32` canvas that is cleared to `background` and returns its surface as an image
+pub fn render<F: FnOnce(&mut Canvas<u32, &mut [u32]>)>(
+    surface_size: (usize, usize),
+    canvas_size: (usize, usize),
+    background: u32,
+    f: F,
+) -> Image<u32, Vec<u32>> {
+    let mut buf = vec![background; surface_size.0 * surface_size.1];
+    f(&mut Canvas::new(&mut buf, surface_size, canvas_size).borrowed());
+    Image::new(buf, surface_size.0, surface_size.1)
+}
+
+/// Error returned when a rendered image doesn't match its reference.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// There is no reference image at the path yet
+    Missing(PathBuf),
+    /// The reference image has a different `(width, height)` than the rendered image
+    SizeMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    /// Some pixels differ by more than the tolerance, the diff image was written to `diff`
+    Mismatch {
+        pixels: usize,
+        max_difference: u8,
+        diff: PathBuf,
+    },
+    /// The reference image couldn't be decoded
+    Decode(PathBuf, DecodeError),
+    /// Reading or writing an image failed
+    Io(io::Error),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(path) => write!(
+                f,
+                "reference image {} is missing, run with {BLESS_VAR}=1 to create it",
+                path.display()
+            ),
+            Self::SizeMismatch { expected, actual } => write!(
+                f,
+                "rendered image is {}x{} but the reference is {}x{}",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+            Self::Mismatch {
+                pixels,
+                max_difference,
+                diff,
+            } => write!(
+                f,
+                "{pixels} pixels differ by up to {max_difference}, see {}",
+                diff.display()
+            ),
+            Self::Decode(path, error) => write!(f, "{}: {error}", path.display()),
+            Self::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Compares rendered images to reference images in a directory.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    dir: PathBuf,
+    tolerance: u8,
+}
+
+impl Snapshot {
+    /// Creates a `Snapshot` for the references in `dir`, usually
+    /// `concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots")`
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            tolerance: 0,
+        }
+    }
+
+    /// Sets by how much every channel of a pixel may differ from the reference, the default is an exact match
+    pub fn with_tolerance(self, tolerance: u8) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    /// Returns `true` if the references are rewritten instead of compared
+    pub fn is_blessing() -> bool {
+        std::env::var_os(BLESS_VAR).is_some_and(|v| !v.is_empty() && v != "0")
+    }
+
+    /// Writes `image` to `path` as PPM if that is its extension or as PNG otherwise
+    fn write<P: PixelFormat, B: AsRef<[P]>>(path: &Path, image: &Image<P, B>) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut sink = IoSink(io::BufWriter::new(fs::File::create(path)?));
+        if path.extension().is_some_and(|e| e == "ppm") {
+            write_ppm(&mut sink, image, PnmEncoding::Binary)?;
+        } else {
+            write_png(&mut sink, image, PngColor::Rgba)?;
+        }
+        sink.0.flush()
+    }
+
+    fn read(path: &Path) -> Result<Image<Color, Vec<Color>>, SnapshotError> {
+        let data = match fs::read(path) {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Err(SnapshotError::Missing(path.into()))
+            }
+            data => data?,
+        };
+        let image = if path.extension().is_some_and(|e| e == "ppm") {
+            read_ppm(&data)
+        } else {
+            crate::Png::decode(&data).map(|png| png.to_image())
+        };
+        image.map_err(|error| SnapshotError::Decode(path.into(), error))
+    }
+
+    /// Returns the path next to the reference `name` with another suffix, like `rect.diff.png` for `rect.png`
+    fn sibling(&self, name: &str, suffix: &str) -> PathBuf {
+        let stem = Path::new(name).with_extension("");
+        let mut file = String::from(stem.to_string_lossy());
+        file.push_str(suffix);
+        self.dir.join(file)
+    }
+
+    /// Compares `image` to the reference image `name`, or writes it as the reference in blessing mode.
+    ///
+    /// Alpha is compared too, except for PPM references that are always opaque.
+    pub fn compare<P: PixelFormat, B: AsRef<[P]>>(
+        &self,
+        name: &str,
+        image: &Image<P, B>,
+    ) -> Result<(), SnapshotError> {
+        let path = self.dir.join(name);
+        let diff_path = self.sibling(name, ".diff.png");
+        let actual_path = self.sibling(name, ".actual.png");
+        if Self::is_blessing() {
+            for stale in [&diff_path, &actual_path] {
+                let _ = fs::remove_file(stale);
+            }
+            return Ok(Self::write(&path, image)?);
+        }
+
+        let reference = Self::read(&path)?;
+        if reference.size() != image.size() {
+            return Err(SnapshotError::SizeMismatch {
+                expected: reference.size(),
+                actual: image.size(),
+            });
+        }
+        let opaque = path.extension().is_some_and(|e| e == "ppm");
+        let (mut pixels, mut max_difference) = (0, 0);
+        let mut diff = Vec::with_capacity(image.width() * image.height());
+        for (expected, actual) in reference.rows().flatten().zip(image.rows().flatten()) {
+            let mut actual = actual.to_color();
+            if opaque {
+                actual.a = 255;
+            }
+            let difference = [
+                expected.r.abs_diff(actual.r),
+                expected.g.abs_diff(actual.g),
+                expected.b.abs_diff(actual.b),
+                expected.a.abs_diff(actual.a),
+            ]
+            .into_iter()
+            .max()
+            .unwrap();
+            max_difference = max_difference.max(difference);
+            if difference > self.tolerance {
+                pixels += 1;
+                diff.push(Color::rgb(255, 0, 0));
+            } else {
+                // Matching pixels are dimmed so that the mismatches stand out
+                let v = expected.luma() / 3;
+                diff.push(Color::rgb(v, v, v));
+            }
+        }
+        if pixels == 0 {
+            return Ok(());
+        }
+        Self::write(&diff_path, &Image::new(diff, image.width(), image.height()))?;
+        Self::write(&actual_path, image)?;
+        Err(SnapshotError::Mismatch {
+            pixels,
+            max_difference,
+            diff: diff_path,
+        })
+    }
+
+    /// Like `compare`, but panics with a description of the mismatch
+    #[track_caller]
+    pub fn assert<P: PixelFormat, B: AsRef<[P]>>(&self, name: &str, image: &Image<P, B>) {
+        if let Err(error) = self.compare(name, image) {
+            panic!("snapshot {name} doesn't match: {error}");
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use crate::{Canvas, Draw, Font, Glyph};
+
+/// `draw`able string, rendered with the glyphs of a `Font` and painted with any brush.
+///
+/// The position `Text` is drawn at is the top left corner of the first line, `'\n'` starts a new line below it.
+/// Characters without a glyph are drawn with `Font::glyph_or_fallback`.
+pub struct Text<'a, F: Font, D: Draw> {
+    pub text: &'a str,
+    pub font: &'a F,
+    pub d: &'a D,
+    /// Extra canvas pixels between two glyphs, can be negative
+    pub letter_spacing: i32,
+    /// Extra canvas pixels between two lines, can be negative
+    pub line_spacing: i32,
+    /// Every glyph pixel is drawn as a `scale`x`scale` square
+    pub scale: usize,
+}
+
+/// A character placed by `Text::layout` or `TextBox::layout`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PositionedGlyph<'a> {
+    pub c: char,
+    pub glyph: Glyph<'a>,
+    /// Canvas position of the pen, the left edge of the glyph at the top of its line. The glyph's bitmap is
+    /// `glyph.offset` font pixels (multiplied by the scale) away from it.
+    pub x: i32,
+    pub y: i32,
+}
+
+impl<'a, F: Font, D: Draw> Text<'a, F, D> {
+    /// Creates unscaled text without extra spacing
+    pub fn new(text: &'a str, font: &'a F, d: &'a D) -> Self {
+        Self {
+            text,
+            font,
+            d,
+            letter_spacing: 0,
+            line_spacing: 0,
+            scale: 1,
+        }
+    }
+
+    /// Returns the text with different letter and line spacing
+    pub fn with_spacing(self, letter_spacing: i32, line_spacing: i32) -> Self {
+        Self {
+            letter_spacing,
+            line_spacing,
+            ..self
+        }
+    }
+
+    /// Returns the text with a different scale factor
+    pub fn with_scale(self, scale: usize) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Returns the height of a line in canvas pixels
+    pub fn line_height(&self) -> usize {
+        self.font.line_height() * self.scale
+    }
+
+    /// Returns the distance between the tops of two lines in canvas pixels
+    fn line_advance(&self) -> i32 {
+        self.line_height() as i32 + self.line_spacing
+    }
+
+    /// Returns the distance the pen moves after drawing `glyph`, including the letter spacing
+    fn advance(&self, glyph: &Glyph) -> i32 {
+        (glyph.advance * self.scale) as i32 + self.letter_spacing
+    }
+
+    /// Returns the width of a single line of text in canvas pixels, ignoring line breaks
+    pub fn line_width(&self, line: &str) -> usize {
+        let mut width = 0;
+        let mut any = false;
+        for glyph in line.chars().filter_map(|c| self.font.glyph_or_fallback(c)) {
+            width += self.advance(&glyph);
+            any = true;
+        }
+        if any {
+            width -= self.letter_spacing;
+        }
+        width.max(0) as usize
+    }
+
+    /// Returns the `(width, height)` of `text` when it is drawn with the font, spacing and scale of this text
+    pub fn measure(&self, text: &str) -> (usize, usize) {
+        let (width, lines) = text
+            .split('\n')
+            .fold((0, 0), |(w, n), line| (self.line_width(line).max(w), n + 1));
+        let height = lines * self.line_advance() - self.line_spacing;
+        (width, height.max(0) as usize)
+    }
+
+    /// Returns the `(width, height)` of the whole text
+    pub fn size(&self) -> (usize, usize) {
+        self.measure(self.text)
+    }
+
+    /// Splits the first line off `rest`, breaking it at the last space that keeps the line within `max_width`.
+    /// Words that don't fit on a line of their own are broken between characters, leading spaces are dropped if the
+    /// first word doesn't fit after them.
+    fn split_line<'t>(
+        &self,
+        rest: &'t str,
+        max_width: Option<usize>,
+    ) -> (&'t str, Option<&'t str>) {
+        let (paragraph, after) = match rest.find('\n') {
+            Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+            None => (rest, None),
+        };
+        let Some(max_width) = max_width else {
+            return (paragraph, after);
+        };
+        let (mut start, mut width, mut any) = (0, 0, false);
+        let mut last_space = None;
+        for (i, c) in paragraph.char_indices() {
+            let Some(glyph) = self.font.glyph_or_fallback(c) else {
+                continue;
+            };
+            let glyph_width = (glyph.advance * self.scale) as i32;
+            if c != ' ' && width + glyph_width > max_width as i32 {
+                if any {
+                    let (line, next) = match last_space {
+                        Some(space) => (paragraph[start..space].trim_end(), &rest[space..]),
+                        None => (&paragraph[start..i], &rest[i..]),
+                    };
+                    return (line, Some(next.trim_start_matches(' ')));
+                }
+                // Only spaces so far, the line starts at this character
+                (start, width) = (i, 0);
+            }
+            // Spaces only become break points once the line has content
+            if c == ' ' {
+                if any {
+                    last_space = Some(i);
+                }
+            } else {
+                any = true;
+            }
+            width += glyph_width + self.letter_spacing;
+        }
+        (&paragraph[start..], after)
+    }
+
+    /// Returns an iterator over the lines of the text, wrapped to `max_width` canvas pixels if it is given
+    fn lines(&self, max_width: Option<usize>) -> impl Iterator<Item = &'a str> + '_ {
+        let mut rest = Some(self.text);
+        core::iter::from_fn(move || {
+            let (line, next) = self.split_line(rest?, max_width);
+            rest = next;
+            Some(line)
+        })
+    }
+
+    /// Calls `f` with every glyph of `run`, starting with the pen at (`x`, `y`), and returns the pen position after it
+    fn place_run<G: FnMut(PositionedGlyph<'a>)>(
+        &self,
+        run: &str,
+        mut x: i32,
+        y: i32,
+        f: &mut G,
+    ) -> i32 {
+        for c in run.chars() {
+            if let Some(glyph) = self.font.glyph_or_fallback(c) {
+                f(PositionedGlyph { c, glyph, x, y });
+                x += self.advance(&glyph);
+            }
+        }
+        x
+    }
+
+    /// Calls `f` with the position of every glyph when the text is drawn at (`x`, `y`)
+    pub fn layout<G: FnMut(PositionedGlyph<'a>)>(&self, x: i32, y: i32, mut f: G) {
+        for (i, line) in self.lines(None).enumerate() {
+            self.place_run(line, x, y + i as i32 * self.line_advance(), &mut f);
+        }
+    }
+
+    /// Draws the bitmap of a placed glyph, skipping pixels outside of the `(x, y, w, h)` rectangle `clip`
+    fn draw_glyph(
+        &self,
+        canvas: &mut Canvas<D::T, &mut [D::T]>,
+        placed: &PositionedGlyph,
+        clip: Option<(i32, i32, usize, usize)>,
+    ) {
+        let scale = self.scale as i32;
+        let glyph = &placed.glyph;
+        let left = placed.x + glyph.offset.0 * scale;
+        let top = placed.y + glyph.offset.1 * scale;
+        let visible = |x: i32, y: i32| match clip {
+            Some((cx, cy, cw, ch)) => {
+                x >= cx && y >= cy && x < cx + cw as i32 && y < cy + ch as i32
+            }
+            None => true,
+        };
+        for gy in 0..glyph.height {
+            for gx in 0..glyph.width {
+                if !glyph.pixel(gx, gy) {
+                    continue;
+                }
+                let (px, py) = (left + gx as i32 * scale, top + gy as i32 * scale);
+                for sy in 0..scale {
+                    for sx in 0..scale {
+                        if visible(px + sx, py + sy) {
+                            self.d.draw(canvas, px + sx, py + sy);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<P: Clone, F: Font, D: Draw<T = P>> Draw for Text<'_, F, D> {
+    type T = P;
+
+    fn draw(&self, canvas: &mut Canvas<Self::T, &mut [Self::T]>, x: i32, y: i32) {
+        self.layout(x, y, |placed| self.draw_glyph(canvas, &placed, None));
+    }
+}
+
+/// Horizontal alignment of the lines in a `TextBox`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Vertical alignment of the lines in a `TextBox`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VerticalAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// `draw`able `Text` that is laid out inside a box of `size` canvas pixels whose top left corner is the position
+/// it is drawn at.
+///
+/// Lines are word wrapped to the width of the box and aligned inside it. Lines that don't fit into the box are
+/// left out, and if an `ellipsis` is set, the last visible line, like any line that is too long, ends with it.
+/// Pixels outside of the box are never drawn.
+pub struct TextBox<'a, F: Font, D: Draw> {
+    pub text: Text<'a, F, D>,
+    pub size: (usize, usize),
+    pub align: Align,
+    pub vertical_align: VerticalAlign,
+    /// Breaks lines that are wider than the box at spaces
+    pub wrap: bool,
+    /// Text that replaces the end of truncated lines, like `"..."`
+    pub ellipsis: Option<&'a str>,
+}
+
+impl<'a, F: Font, D: Draw> TextBox<'a, F, D> {
+    /// Creates a top left aligned box that wraps its text and cuts off what doesn't fit
+    pub fn new(text: Text<'a, F, D>, size: (usize, usize)) -> Self {
+        Self {
+            text,
+            size,
+            align: Align::Left,
+            vertical_align: VerticalAlign::Top,
+            wrap: true,
+            ellipsis: None,
+        }
+    }
+
+    /// Returns the box with different alignments
+    pub fn with_align(self, align: Align, vertical_align: VerticalAlign) -> Self {
+        Self {
+            align,
+            vertical_align,
+            ..self
+        }
+    }
+
+    /// Returns the box with word wrapping enabled or disabled
+    pub fn with_wrap(self, wrap: bool) -> Self {
+        Self { wrap, ..self }
+    }
+
+    /// Returns the box with truncated lines ending in `ellipsis`
+    pub fn with_ellipsis(self, ellipsis: &'a str) -> Self {
+        Self {
+            ellipsis: Some(ellipsis),
+            ..self
+        }
+    }
+
+    /// Returns the number of lines that fit into the box, at least one
+    fn max_lines(&self) -> usize {
+        let available = self.size.1 as i32 - self.text.line_height() as i32;
+        (available.max(0) / self.text.line_advance().max(1)) as usize + 1
+    }
+
+    /// Returns the longest prefix of `line` that fits into the box together with the ellipsis
+    fn truncate<'t>(&self, line: &'t str, ellipsis: &str) -> &'t str {
+        let text = &self.text;
+        let available = self.size.0 as i32 - text.line_width(ellipsis) as i32 - text.letter_spacing;
+        let mut end = 0;
+        for (i, c) in line.char_indices() {
+            let next = i + c.len_utf8();
+            if text.line_width(&line[..next]) as i32 > available {
+                break;
+            }
+            end = next;
+        }
+        line[..end].trim_end()
+    }
+
+    /// Calls `f` with the position of every visible glyph when the box is drawn at (`x`, `y`)
+    pub fn layout<G: FnMut(PositionedGlyph<'a>)>(&self, x: i32, y: i32, mut f: G) {
+        let text = &self.text;
+        let (w, h) = self.size;
+        let max_width = self.wrap.then_some(w);
+        let total = text.lines(max_width).count();
+        let visible = total.min(self.max_lines());
+        let block = visible as i32 * text.line_advance() - text.line_spacing;
+        let mut pen_y = y + match self.vertical_align {
+            VerticalAlign::Top => 0,
+            VerticalAlign::Middle => (h as i32 - block) / 2,
+            VerticalAlign::Bottom => h as i32 - block,
+        };
+        for (i, line) in text.lines(max_width).take(visible).enumerate() {
+            let cut_off = total > visible && i == visible - 1;
+            let width = text.line_width(line);
+            let (line, ellipsis) = match self.ellipsis {
+                Some(ellipsis) if cut_off || width > w => (self.truncate(line, ellipsis), ellipsis),
+                _ => (line, ""),
+            };
+            let width = match (line.is_empty(), ellipsis.is_empty()) {
+                (_, true) => text.line_width(line) as i32,
+                (true, false) => text.line_width(ellipsis) as i32,
+                (false, false) => {
+                    (text.line_width(line) + text.line_width(ellipsis)) as i32 + text.letter_spacing
+                }
+            };
+            let free = w as i32 - width;
+            let pen_x = x + match self.align {
+                Align::Left => 0,
+                Align::Center => free / 2,
+                Align::Right => free,
+            };
+            let pen_x = text.place_run(line, pen_x, pen_y, &mut f);
+            text.place_run(ellipsis, pen_x, pen_y, &mut f);
+            pen_y += text.line_advance();
+        }
+    }
+}
+
+impl<P: Clone, F: Font, D: Draw<T = P>> Draw for TextBox<'_, F, D> {
+    type T = P;
+
+    fn draw(&self, canvas: &mut Canvas<Self::T, &mut [Self::T]>, x: i32, y: i32) {
+        let clip = Some((x, y, self.size.0, self.size.1));
+        self.layout(x, y, |placed| self.text.draw_glyph(canvas, &placed, clip));
+    }
+}
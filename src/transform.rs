@@ -0,0 +1,222 @@
+use crate::{math, Canvas, Color, Draw, Image, PixelFormat};
+
+/// 2D affine transformation that maps (`x`, `y`) to (`a * x + c * y + e`, `b * x + d * y + f`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub e: f32,
+    pub f: f32,
+}
+
+impl Default for Affine {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine {
+    pub const IDENTITY: Affine = Affine {
+        a: 1.,
+        b: 0.,
+        c: 0.,
+        d: 1.,
+        e: 0.,
+        f: 0.,
+    };
+
+    pub const fn translate(x: f32, y: f32) -> Self {
+        Self {
+            e: x,
+            f: y,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub const fn scale(x: f32, y: f32) -> Self {
+        Self {
+            a: x,
+            d: y,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Rotation by `angle` radians, clockwise on the screen since the y axis points down
+    pub fn rotate(angle: f32) -> Self {
+        let (sin, cos) = (
+            math::sin(angle as f64) as f32,
+            math::cos(angle as f64) as f32,
+        );
+        Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            e: 0.,
+            f: 0.,
+        }
+    }
+
+    /// Shear that moves x by `x * y` and y by `y * x`
+    pub const fn shear(x: f32, y: f32) -> Self {
+        Self {
+            b: y,
+            c: x,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Returns the transformation that applies `self` first and `next` after it
+    pub fn then(self, next: Affine) -> Self {
+        Self {
+            a: next.a * self.a + next.c * self.b,
+            b: next.b * self.a + next.d * self.b,
+            c: next.a * self.c + next.c * self.d,
+            d: next.b * self.c + next.d * self.d,
+            e: next.a * self.e + next.c * self.f + next.e,
+            f: next.b * self.e + next.d * self.f + next.f,
+        }
+    }
+
+    /// Returns the inverse transformation, or `None` if the transformation collapses the plane
+    pub fn invert(self) -> Option<Self> {
+        let det = self.a * self.d - self.b * self.c;
+        if det == 0. || !det.is_finite() {
+            return None;
+        }
+        let (a, b, c, d) = (self.d / det, -self.b / det, -self.c / det, self.a / det);
+        Some(Self {
+            a,
+            b,
+            c,
+            d,
+            e: -(a * self.e + c * self.f),
+            f: -(b * self.e + d * self.f),
+        })
+    }
+
+    /// Transforms a point
+    pub fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+}
+
+/// How `Transformed` reads the image at a non-integer position.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Sampling {
+    /// Uses the pixel the position falls in
+    #[default]
+    Nearest,
+    /// Interpolates between the 2x2 closest pixels
+    Bilinear,
+}
+
+impl<T: PixelFormat, B: AsRef<[T]>> Image<T, B> {
+    /// Returns the pixel at the non-integer position (`u`, `v`) in image coordinates (pixel centers are at `.5`),
+    /// or `None` if the position is outside of the image.
+    pub fn sample_at(&self, u: f32, v: f32, sampling: Sampling) -> Option<T> {
+        if u < 0. || v < 0. || u >= self.width() as f32 || v >= self.height() as f32 {
+            return None;
+        }
+        match sampling {
+            Sampling::Nearest => self.get(u as usize, v as usize).copied(),
+            Sampling::Bilinear => {
+                let (u, v) = (u - 0.5, v - 0.5);
+                let (x0, y0) = (math::floor(u as f64) as i64, math::floor(v as f64) as i64);
+                let (tx, ty) = (u - x0 as f32, v - y0 as f32);
+                let pixel = |x: i64, y: i64| {
+                    let x = x.clamp(0, self.width() as i64 - 1) as usize;
+                    let y = y.clamp(0, self.height() as i64 - 1) as usize;
+                    let c = self.get(x, y).map_or(Color::TRANSPARENT, |p| p.to_color());
+                    [c.r, c.g, c.b, c.a].map(|c| c as f32)
+                };
+                let (p00, p10) = (pixel(x0, y0), pixel(x0 + 1, y0));
+                let (p01, p11) = (pixel(x0, y0 + 1), pixel(x0 + 1, y0 + 1));
+                let mut out = [0u8; 4];
+                for (i, o) in out.iter_mut().enumerate() {
+                    let top = p00[i] + (p10[i] - p00[i]) * tx;
+                    let bottom = p01[i] + (p11[i] - p01[i]) * tx;
+                    *o = math::round((top + (bottom - top) * ty) as f64) as u8;
+                }
+                Some(T::from_color(Color::rgba(out[0], out[1], out[2], out[3])))
+            }
+        }
+    }
+}
+
+/// `draw`able image with an arbitrary affine transformation, e.g. rotated, scaled or sheared sprites.
+///
+/// `transform` maps image coordinates to canvas coordinates relative to the position the shape is drawn at.
+/// Every canvas pixel inside the transformed bounding box is mapped back into the image with the inverse
+/// transformation and written with `Canvas::put`, so canvas scaling still applies. The bounding box is clipped to
+/// the canvas, pixels outside of it are never sampled.
+pub struct Transformed<'a, T, B: AsRef<[T]>> {
+    pub image: &'a Image<T, B>,
+    pub transform: Affine,
+    pub sampling: Sampling,
+}
+
+impl<T: PixelFormat, B: AsRef<[T]>> Draw for Transformed<'_, T, B> {
+    type T = T;
+    fn draw(&self, canvas: &mut Canvas<T, &mut [T]>, x: i32, y: i32) {
+        let Some(inverse) = self.transform.invert() else {
+            return;
+        };
+        let (w, h) = (self.image.width() as f32, self.image.height() as f32);
+        let corners = [(0., 0.), (w, 0.), (0., h), (w, h)].map(|(u, v)| self.transform.apply(u, v));
+        let (mut min_x, mut min_y) = (f32::MAX, f32::MAX);
+        let (mut max_x, mut max_y) = (f32::MIN, f32::MIN);
+        for (cx, cy) in corners {
+            min_x = min_x.min(cx);
+            min_y = min_y.min(cy);
+            max_x = max_x.max(cx);
+            max_y = max_y.max(cy);
+        }
+
+        // The bounding box in canvas coordinates, clipped to the canvas
+        let (canvas_w, canvas_h) = canvas.canvas_size();
+        let clip = |min: f32, max: f32, offset: i32, len: usize| {
+            let start = (math::floor(min as f64) + offset as f64).max(0.);
+            let end = (math::floor(max as f64) + offset as f64).min(len as f64 - 1.);
+            start as i32..=end as i32
+        };
+
+        for cy in clip(min_y, max_y, y, canvas_h) {
+            for cx in clip(min_x, max_x, x, canvas_w) {
+                let px = (cx as i64 - x as i64) as f32;
+                let py = (cy as i64 - y as i64) as f32;
+                let (u, v) = inverse.apply(px + 0.5, py + 0.5);
+                if let Some(p) = self.image.sample_at(u, v, self.sampling) {
+                    canvas.put(cx, cy, p);
+                }
+            }
+        }
+    }
+}
+
+impl<T: PixelFormat> Canvas<T, &mut [T]> {
+    /// `draw`s `image` transformed by `transform` at the specified position on the canvas, see `Transformed`
+    pub fn blit_transformed<B: AsRef<[T]>>(
+        &mut self,
+        x: i32,
+        y: i32,
+        image: &Image<T, B>,
+        transform: Affine,
+        sampling: Sampling,
+    ) {
+        self.draw(
+            x,
+            y,
+            &Transformed {
+                image,
+                transform,
+                sampling,
+            },
+        );
+    }
+}
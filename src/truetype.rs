@@ -0,0 +1,759 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::cell::{Ref, RefCell};
+
+use crate::{math, Affine, BlendSpace, Canvas, Color, Draw, FontError, Image, Rgba};
+
+/// Composite glyphs can reference each other, this limits how deep they are followed
+const MAX_COMPOSITE_DEPTH: usize = 8;
+/// Every component can reference another composite glyph with thousands of components, this limits how many
+/// components of one outline are followed in total
+const MAX_COMPONENTS: usize = 1024;
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([
+        *data.get(offset)?,
+        *data.get(offset + 1)?,
+    ]))
+}
+
+fn i16_at(data: &[u8], offset: usize) -> Option<i16> {
+    u16_at(data, offset).map(|v| v as i16)
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn ceil(x: f32) -> f32 {
+    -math::floor(-x as f64) as f32
+}
+
+fn floor(x: f32) -> f32 {
+    math::floor(x as f64) as f32
+}
+
+/// Returns the table with the given tag from the table directory of a font
+fn table<'a>(data: &'a [u8], tag: &[u8; 4]) -> Result<&'a [u8], FontError> {
+    let count = u16_at(data, 4).ok_or(FontError::Truncated)? as usize;
+    for record in (0..count).map(|i| 12 + 16 * i) {
+        let record_tag = data.get(record..record + 4).ok_or(FontError::Truncated)?;
+        if record_tag == tag {
+            let offset = u32_at(data, record + 8).ok_or(FontError::Truncated)? as usize;
+            let len = u32_at(data, record + 12).ok_or(FontError::Truncated)? as usize;
+            return data.get(offset..offset + len).ok_or(FontError::Truncated);
+        }
+    }
+    Err(FontError::InvalidHeader)
+}
+
+/// Character to glyph mapping subtable of the `cmap` table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CharMap<'a> {
+    /// Segment mapping to delta values, covers the Basic Multilingual Plane
+    Format4(&'a [u8]),
+    /// Segmented coverage, covers all of Unicode
+    Format12(&'a [u8]),
+}
+
+impl<'a> CharMap<'a> {
+    /// Picks the best Unicode subtable, preferring full Unicode coverage
+    fn parse(cmap: &'a [u8]) -> Option<Self> {
+        let count = u16_at(cmap, 2)? as usize;
+        let mut best = None;
+        for record in (0..count).map(|i| 4 + 8 * i) {
+            let platform = u16_at(cmap, record)?;
+            let encoding = u16_at(cmap, record + 2)?;
+            let subtable = cmap.get(u32_at(cmap, record + 4)? as usize..)?;
+            let unicode = platform == 0 || (platform == 3 && (encoding == 1 || encoding == 10));
+            match u16_at(subtable, 0)? {
+                12 if unicode => return Some(Self::Format12(subtable)),
+                4 if unicode => best = Some(Self::Format4(subtable)),
+                _ => {}
+            }
+        }
+        best
+    }
+
+    fn glyph_index(self, c: char) -> Option<u16> {
+        let c = c as u32;
+        match self {
+            Self::Format4(table) => {
+                let c = u16::try_from(c).ok()?;
+                let seg_count_x2 = u16_at(table, 6)? as usize;
+                let end_codes = 14;
+                let start_codes = 16 + seg_count_x2;
+                let deltas = start_codes + seg_count_x2;
+                let range_offsets = deltas + seg_count_x2;
+                // Binary search for the first segment that ends at or after `c`
+                let (mut lo, mut hi) = (0, seg_count_x2 / 2);
+                while lo < hi {
+                    let mid = (lo + hi) / 2;
+                    if u16_at(table, end_codes + 2 * mid)? < c {
+                        lo = mid + 1;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                let segment = 2 * lo;
+                let start = u16_at(table, start_codes + segment)?;
+                if lo == seg_count_x2 / 2 || c < start {
+                    return None;
+                }
+                let delta = u16_at(table, deltas + segment)?;
+                let range_offset = u16_at(table, range_offsets + segment)? as usize;
+                let index = if range_offset == 0 {
+                    c.wrapping_add(delta)
+                } else {
+                    let address = range_offsets + segment + range_offset + 2 * (c - start) as usize;
+                    match u16_at(table, address)? {
+                        0 => 0,
+                        g => g.wrapping_add(delta),
+                    }
+                };
+                (index != 0).then_some(index)
+            }
+            Self::Format12(table) => {
+                let groups = u32_at(table, 12)? as usize;
+                let (mut lo, mut hi) = (0, groups);
+                while lo < hi {
+                    let mid = (lo + hi) / 2;
+                    let group = 16 + 12 * mid;
+                    if u32_at(table, group + 4)? < c {
+                        lo = mid + 1;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                let group = 16 + 12 * lo;
+                let start = u32_at(table, group)?;
+                if lo == groups || c < start {
+                    return None;
+                }
+                let index = u32_at(table, group + 8)? + (c - start);
+                u16::try_from(index).ok().filter(|&i| i != 0)
+            }
+        }
+    }
+}
+
+/// Outline font in the TrueType format, which includes OpenType fonts with `glyf` outlines.
+///
+/// The font borrows its data, so it can be loaded from `include_bytes!`. Glyphs are rasterized without hinting and
+/// positioned without kerning. Fonts with CFF outlines and font collections are not supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrueTypeFont<'a> {
+    glyf: &'a [u8],
+    loca: &'a [u8],
+    hmtx: &'a [u8],
+    cmap: CharMap<'a>,
+    long_loca: bool,
+    glyph_count: u16,
+    h_metric_count: u16,
+    units_per_em: u16,
+    ascent: i16,
+    descent: i16,
+    line_gap: i16,
+}
+
+impl<'a> TrueTypeFont<'a> {
+    /// Parses the tables needed to rasterize glyphs
+    pub fn parse(data: &'a [u8]) -> Result<Self, FontError> {
+        match data.get(..4).ok_or(FontError::Truncated)? {
+            [0, 1, 0, 0] | b"true" => {}
+            b"OTTO" | b"ttcf" => return Err(FontError::Unsupported),
+            _ => return Err(FontError::InvalidMagic),
+        }
+        let head = table(data, b"head")?;
+        let maxp = table(data, b"maxp")?;
+        let hhea = table(data, b"hhea")?;
+        let header = |table: &[u8], offset| i16_at(table, offset).ok_or(FontError::Truncated);
+        let units_per_em = header(head, 18)? as u16;
+        if units_per_em == 0 {
+            return Err(FontError::InvalidHeader);
+        }
+        Ok(Self {
+            glyf: table(data, b"glyf")?,
+            loca: table(data, b"loca")?,
+            hmtx: table(data, b"hmtx")?,
+            cmap: CharMap::parse(table(data, b"cmap")?).ok_or(FontError::Unsupported)?,
+            long_loca: header(head, 50)? != 0,
+            glyph_count: header(maxp, 4)? as u16,
+            h_metric_count: header(hhea, 34)? as u16,
+            units_per_em,
+            ascent: header(hhea, 4)?,
+            descent: header(hhea, 6)?,
+            line_gap: header(hhea, 8)?,
+        })
+    }
+
+    /// Returns the number of font units per em square
+    pub fn units_per_em(&self) -> u16 {
+        self.units_per_em
+    }
+
+    /// Returns the distance from the baseline to the top of a line in font units
+    pub fn ascent(&self) -> i16 {
+        self.ascent
+    }
+
+    /// Returns the distance from the baseline to the bottom of a line in font units, usually negative
+    pub fn descent(&self) -> i16 {
+        self.descent
+    }
+
+    /// Returns the extra gap between two lines in font units
+    pub fn line_gap(&self) -> i16 {
+        self.line_gap
+    }
+
+    /// Returns the number of glyphs
+    pub fn glyph_count(&self) -> u16 {
+        self.glyph_count
+    }
+
+    /// Returns the factor that converts font units to pixels when the em square is `size` pixels high
+    pub fn scale(&self, size: f32) -> f32 {
+        size / self.units_per_em as f32
+    }
+
+    /// Returns the index of the glyph of `c`, or `None` if the font doesn't have one.
+    /// Glyph 0 is the `.notdef` glyph fonts draw for missing characters.
+    pub fn glyph_index(&self, c: char) -> Option<u16> {
+        self.cmap.glyph_index(c)
+    }
+
+    /// Returns the horizontal distance to the next glyph in font units
+    pub fn advance_width(&self, glyph: u16) -> u16 {
+        let metric = glyph.min(self.h_metric_count.saturating_sub(1)) as usize;
+        u16_at(self.hmtx, 4 * metric).unwrap_or(0)
+    }
+
+    /// Returns the outline data of a glyph, which is empty for glyphs without an outline like spaces
+    fn glyph_data(&self, glyph: u16) -> Option<&'a [u8]> {
+        if glyph >= self.glyph_count {
+            return None;
+        }
+        let glyph = glyph as usize;
+        let (start, end) = if self.long_loca {
+            (
+                u32_at(self.loca, 4 * glyph)? as usize,
+                u32_at(self.loca, 4 * glyph + 4)? as usize,
+            )
+        } else {
+            (
+                u16_at(self.loca, 2 * glyph)? as usize * 2,
+                u16_at(self.loca, 2 * glyph + 2)? as usize * 2,
+            )
+        };
+        self.glyf.get(start..end.max(start))
+    }
+
+    /// Appends the outline of a glyph, transformed by `transform`, to `lines` as straight line segments.
+    /// `components` is the number of composite components that may still be followed.
+    fn outline(
+        &self,
+        glyph: u16,
+        transform: Affine,
+        depth: usize,
+        components: &mut usize,
+        lines: &mut Vec<Segment>,
+    ) -> Option<()> {
+        let data = self.glyph_data(glyph)?;
+        if data.is_empty() {
+            return Some(());
+        }
+        let contours = i16_at(data, 0)?;
+        if contours >= 0 {
+            simple_outline(data, contours as usize, transform, lines)
+        } else if depth < MAX_COMPOSITE_DEPTH {
+            self.composite_outline(data, transform, depth, components, lines)
+        } else {
+            None
+        }
+    }
+
+    fn composite_outline(
+        &self,
+        data: &[u8],
+        transform: Affine,
+        depth: usize,
+        components: &mut usize,
+        lines: &mut Vec<Segment>,
+    ) -> Option<()> {
+        const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+        const ARGS_ARE_XY_VALUES: u16 = 0x0002;
+        const WE_HAVE_A_SCALE: u16 = 0x0008;
+        const MORE_COMPONENTS: u16 = 0x0020;
+        const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+        const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+        // Scale factors are F2Dot14 fixed point numbers
+        let f2dot14 = |offset| Some(i16_at(data, offset)? as f32 / 16384.);
+
+        let mut p = 10;
+        loop {
+            *components = components.checked_sub(1)?;
+            let flags = u16_at(data, p)?;
+            let component = u16_at(data, p + 2)?;
+            p += 4;
+            let (arg1, arg2) = if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+                p += 4;
+                (i16_at(data, p - 4)? as f32, i16_at(data, p - 2)? as f32)
+            } else {
+                p += 2;
+                (
+                    *data.get(p - 2)? as i8 as f32,
+                    *data.get(p - 1)? as i8 as f32,
+                )
+            };
+            let mut local = Affine::IDENTITY;
+            if flags & WE_HAVE_A_SCALE != 0 {
+                let s = f2dot14(p)?;
+                (local.a, local.d) = (s, s);
+                p += 2;
+            } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+                (local.a, local.d) = (f2dot14(p)?, f2dot14(p + 2)?);
+                p += 4;
+            } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+                (local.a, local.b) = (f2dot14(p)?, f2dot14(p + 2)?);
+                (local.c, local.d) = (f2dot14(p + 4)?, f2dot14(p + 6)?);
+                p += 8;
+            }
+            // Components positioned by matching points are rare and placed without an offset
+            if flags & ARGS_ARE_XY_VALUES != 0 {
+                (local.e, local.f) = (arg1, arg2);
+            }
+            self.outline(
+                component,
+                local.then(transform),
+                depth + 1,
+                components,
+                lines,
+            )?;
+            if flags & MORE_COMPONENTS == 0 {
+                return Some(());
+            }
+        }
+    }
+
+    /// Rasterizes a glyph with its em square `size` pixels high.
+    /// Returns `None` if the glyph doesn't exist or its outline is malformed.
+    pub fn rasterize(&self, glyph: u16, size: f32) -> Option<GlyphBitmap> {
+        let scale = self.scale(size);
+        let mut lines = Vec::new();
+        // Font units have y pointing up, bitmaps have it pointing down
+        let transform = Affine::scale(scale, -scale);
+        let mut components = MAX_COMPONENTS;
+        self.outline(glyph, transform, 0, &mut components, &mut lines)?;
+        let advance = self.advance_width(glyph) as f32 * scale;
+        if lines.is_empty() {
+            return Some(GlyphBitmap {
+                advance,
+                ..GlyphBitmap::default()
+            });
+        }
+        let (mut min, mut max) = ((f32::MAX, f32::MAX), (f32::MIN, f32::MIN));
+        for point in lines.iter().flatten() {
+            min = (min.0.min(point.0), min.1.min(point.1));
+            max = (max.0.max(point.0), max.1.max(point.1));
+        }
+        let (left, top) = (floor(min.0), floor(min.1));
+        let width = (ceil(max.0) - left) as usize;
+        let height = (ceil(max.1) - top) as usize;
+        let mut rasterizer = Rasterizer::new(width, height);
+        for [p0, p1] in lines {
+            rasterizer.line((p0.0 - left, p0.1 - top), (p1.0 - left, p1.1 - top));
+        }
+        Some(GlyphBitmap {
+            width,
+            height,
+            offset: (left as i32, top as i32),
+            advance,
+            coverage: rasterizer.coverage(),
+        })
+    }
+}
+
+type Point = (f32, f32);
+type Segment = [Point; 2];
+
+fn midpoint(a: Point, b: Point) -> Point {
+    ((a.0 + b.0) / 2., (a.1 + b.1) / 2.)
+}
+
+/// Appends a quadratic Bézier curve as line segments, using more segments for more strongly curved curves
+fn quad(p0: Point, control: Point, p2: Point, lines: &mut Vec<Segment>) {
+    let dev = (p0.0 - 2. * control.0 + p2.0, p0.1 - 2. * control.1 + p2.1);
+    let dev = dev.0 * dev.0 + dev.1 * dev.1;
+    if dev < 0.333 {
+        lines.push([p0, p2]);
+        return;
+    }
+    let n = 1 + math::floor(math::sqrt(math::sqrt(3. * dev as f64))) as usize;
+    let mut prev = p0;
+    for i in 1..=n {
+        let t = i as f32 / n as f32;
+        let u = 1. - t;
+        let point = (
+            u * u * p0.0 + 2. * u * t * control.0 + t * t * p2.0,
+            u * u * p0.1 + 2. * u * t * control.1 + t * t * p2.1,
+        );
+        lines.push([prev, point]);
+        prev = point;
+    }
+}
+
+/// Parses the contours of a simple glyph into line segments
+fn simple_outline(
+    data: &[u8],
+    contours: usize,
+    transform: Affine,
+    lines: &mut Vec<Segment>,
+) -> Option<()> {
+    const ON_CURVE: u8 = 0x01;
+    const X_SHORT: u8 = 0x02;
+    const Y_SHORT: u8 = 0x04;
+    const REPEAT: u8 = 0x08;
+    const X_SAME_OR_POSITIVE: u8 = 0x10;
+    const Y_SAME_OR_POSITIVE: u8 = 0x20;
+
+    if contours == 0 {
+        return Some(());
+    }
+    let point_count = u16_at(data, 10 + 2 * (contours - 1))? as usize + 1;
+    let instructions = u16_at(data, 10 + 2 * contours)? as usize;
+    let mut p = 12 + 2 * contours + instructions;
+
+    let mut flags = Vec::with_capacity(point_count);
+    while flags.len() < point_count {
+        let flag = *data.get(p)?;
+        p += 1;
+        let repeat = if flag & REPEAT != 0 {
+            p += 1;
+            *data.get(p - 1)? as usize
+        } else {
+            0
+        };
+        flags.extend(core::iter::repeat_n(flag, repeat + 1));
+    }
+    flags.truncate(point_count);
+
+    let mut read_coordinates = |short: u8, same_or_positive: u8| -> Option<Vec<f32>> {
+        let mut value = 0i32;
+        let mut values = Vec::with_capacity(point_count);
+        for &flag in &flags {
+            if flag & short != 0 {
+                let delta = *data.get(p)? as i32;
+                p += 1;
+                value += if flag & same_or_positive != 0 {
+                    delta
+                } else {
+                    -delta
+                };
+            } else if flag & same_or_positive == 0 {
+                value += i16_at(data, p)? as i32;
+                p += 2;
+            }
+            values.push(value as f32);
+        }
+        Some(values)
+    };
+    let xs = read_coordinates(X_SHORT, X_SAME_OR_POSITIVE)?;
+    let ys = read_coordinates(Y_SHORT, Y_SAME_OR_POSITIVE)?;
+
+    let mut start = 0;
+    for contour in 0..contours {
+        let end = u16_at(data, 10 + 2 * contour)? as usize + 1;
+        if end <= start || end > point_count {
+            return None;
+        }
+        let points: Vec<(Point, bool)> = (start..end)
+            .map(|i| (transform.apply(xs[i], ys[i]), flags[i] & ON_CURVE != 0))
+            .collect();
+        contour_lines(&points, lines);
+        start = end;
+    }
+    Some(())
+}
+
+/// Converts a closed contour of on- and off-curve points into line segments.
+/// Two consecutive off-curve points have an implied on-curve point in the middle.
+fn contour_lines(points: &[(Point, bool)], lines: &mut Vec<Segment>) {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let (start, range) = if first.1 {
+        (first.0, 1..points.len())
+    } else if last.1 {
+        (last.0, 0..points.len() - 1)
+    } else {
+        (midpoint(first.0, last.0), 0..points.len())
+    };
+    let mut current = start;
+    let mut control = None;
+    for (point, on_curve) in points[range].iter().copied().chain([(start, true)]) {
+        match (on_curve, control) {
+            (true, Some(c)) => quad(current, c, point, lines),
+            (true, None) => lines.push([current, point]),
+            (false, Some(c)) => {
+                let mid = midpoint(c, point);
+                quad(current, c, mid, lines);
+                current = mid;
+                control = Some(point);
+                continue;
+            }
+            (false, None) => {
+                control = Some(point);
+                continue;
+            }
+        }
+        current = point;
+        control = None;
+    }
+}
+
+/// Computes the exact area of the outline covered by every pixel.
+///
+/// Every line adds the signed area it covers to the right of it into an accumulation buffer, the running sum of the
+/// buffer along a row is then the coverage of each pixel.
+struct Rasterizer {
+    width: usize,
+    height: usize,
+    areas: Vec<f32>,
+}
+
+impl Rasterizer {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            areas: vec![0.; width * height + 4],
+        }
+    }
+
+    fn line(&mut self, p0: Point, p1: Point) {
+        if p0.1 == p1.1 {
+            return;
+        }
+        let (dir, p0, p1) = if p0.1 < p1.1 {
+            (1., p0, p1)
+        } else {
+            (-1., p1, p0)
+        };
+        let dxdy = (p1.0 - p0.0) / (p1.1 - p0.1);
+        let mut x = p0.0;
+        if p0.1 < 0. {
+            x -= p0.1 * dxdy;
+        }
+        for y in p0.1.max(0.) as usize..self.height.min(ceil(p1.1) as usize) {
+            let row = y * self.width;
+            let dy = ((y + 1) as f32).min(p1.1) - (y as f32).max(p0.1);
+            let x_next = x + dxdy * dy;
+            let d = dy * dir;
+            let (x0, x1) = if x < x_next { (x, x_next) } else { (x_next, x) };
+            let x0_floor = floor(x0);
+            let x0i = x0_floor.max(0.) as usize;
+            let x1_ceil = ceil(x1);
+            let x1i = x1_ceil.max(0.) as usize;
+            if x1i <= x0i + 1 {
+                // The line stays within one pixel on this row
+                let xm = 0.5 * (x + x_next) - x0_floor;
+                self.areas[row + x0i] += d - d * xm;
+                self.areas[row + x0i + 1] += d * xm;
+            } else {
+                let s = 1. / (x1 - x0);
+                let x0f = x0 - x0_floor;
+                let a0 = 0.5 * s * (1. - x0f) * (1. - x0f);
+                let x1f = x1 - x1_ceil + 1.;
+                let am = 0.5 * s * x1f * x1f;
+                self.areas[row + x0i] += d * a0;
+                if x1i == x0i + 2 {
+                    self.areas[row + x0i + 1] += d * (1. - a0 - am);
+                } else {
+                    let a1 = s * (1.5 - x0f);
+                    self.areas[row + x0i + 1] += d * (a1 - a0);
+                    for xi in x0i + 2..x1i - 1 {
+                        self.areas[row + xi] += d * s;
+                    }
+                    let a2 = a1 + (x1i - x0i - 3) as f32 * s;
+                    self.areas[row + x1i - 1] += d * (1. - a2 - am);
+                }
+                self.areas[row + x1i] += d * am;
+            }
+            x = x_next;
+        }
+    }
+
+    fn coverage(&self) -> Vec<u8> {
+        let mut sum = 0.;
+        self.areas[..self.width * self.height]
+            .iter()
+            .map(|area| {
+                sum += area;
+                (sum.abs().min(1.) * 255. + 0.5) as u8
+            })
+            .collect()
+    }
+}
+
+/// Anti-aliased bitmap of a rasterized outline glyph.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GlyphBitmap {
+    pub width: usize,
+    pub height: usize,
+    /// Position of the top left corner of the bitmap relative to the pen, which is on the baseline
+    pub offset: (i32, i32),
+    /// Horizontal distance to the next pen position in pixels
+    pub advance: f32,
+    /// Coverage of every pixel, row by row, from 0 outside of the outline to 255 fully inside of it
+    pub coverage: Vec<u8>,
+}
+
+impl GlyphBitmap {
+    /// Returns the coverage as an `Image`, e.g. to use it as a mask
+    pub fn image(&self) -> Image<u8, &[u8]> {
+        Image::new(&self.coverage, self.width, self.height)
+    }
+}
+
+/// Rasterizes the glyphs of a `TrueTypeFont` at one size and keeps them, so every glyph is only rasterized once.
+///
+/// The cache uses interior mutability, so it can be shared by all the `OutlineText`s that use the same font and size.
+#[derive(Debug)]
+pub struct GlyphCache<'a> {
+    font: TrueTypeFont<'a>,
+    size: f32,
+    glyphs: RefCell<BTreeMap<u16, GlyphBitmap>>,
+}
+
+impl<'a> GlyphCache<'a> {
+    /// Creates an empty cache for glyphs whose em square is `size` pixels high
+    pub fn new(font: TrueTypeFont<'a>, size: f32) -> Self {
+        Self {
+            font,
+            size,
+            glyphs: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn font(&self) -> &TrueTypeFont<'a> {
+        &self.font
+    }
+
+    pub fn size(&self) -> f32 {
+        self.size
+    }
+
+    /// Returns the number of cached glyphs
+    pub fn len(&self) -> usize {
+        self.glyphs.borrow().len()
+    }
+
+    /// Returns `true` if no glyphs are cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached glyphs
+    pub fn clear(&mut self) {
+        self.glyphs.get_mut().clear();
+    }
+
+    /// Returns the distance from the top of a line to the baseline in pixels
+    pub fn ascent(&self) -> f32 {
+        self.font.ascent() as f32 * self.font.scale(self.size)
+    }
+
+    /// Returns the distance between the baselines of two lines in pixels
+    pub fn line_height(&self) -> f32 {
+        let font = &self.font;
+        (font.ascent() as i32 - font.descent() as i32 + font.line_gap() as i32) as f32
+            * font.scale(self.size)
+    }
+
+    /// Returns the bitmap of `c`, rasterizing it if it isn't cached yet.
+    /// Characters the font doesn't have use its `.notdef` glyph.
+    pub fn glyph(&self, c: char) -> Ref<'_, GlyphBitmap> {
+        let index = self.font.glyph_index(c).unwrap_or(0);
+        if !self.glyphs.borrow().contains_key(&index) {
+            let bitmap = self.font.rasterize(index, self.size).unwrap_or_default();
+            self.glyphs.borrow_mut().insert(index, bitmap);
+        }
+        Ref::map(self.glyphs.borrow(), |glyphs| &glyphs[&index])
+    }
+
+    /// Returns the `(width, height)` of the box `text` occupies when it is drawn, in pixels
+    pub fn measure(&self, text: &str) -> (usize, usize) {
+        let mut width = 0f32;
+        let mut lines = 0;
+        for line in text.split('\n') {
+            width = width.max(line.chars().map(|c| self.glyph(c).advance).sum());
+            lines += 1;
+        }
+        (
+            ceil(width) as usize,
+            ceil(lines as f32 * self.line_height()) as usize,
+        )
+    }
+}
+
+/// `draw`able anti-aliased text that is blended into `u32` canvases.
+///
+/// The position it is drawn at is the top left corner of the first line, `'\n'` starts a new line. Coordinates are
+/// canvas coordinates, so the glyphs of the cache should be rasterized at the size they have on the canvas.
+pub struct OutlineText<'a> {
+    pub text: &'a str,
+    pub cache: &'a GlyphCache<'a>,
+    pub color: Color,
+    pub space: BlendSpace,
+}
+
+impl<'a> OutlineText<'a> {
+    /// Creates text that is blended in `BlendSpace::Srgb`
+    pub fn new(text: &'a str, cache: &'a GlyphCache<'a>, color: Color) -> Self {
+        Self {
+            text,
+            cache,
+            color,
+            space: BlendSpace::Srgb,
+        }
+    }
+
+    /// Returns the text blended in a different `BlendSpace`
+    pub fn with_space(self, space: BlendSpace) -> Self {
+        Self { space, ..self }
+    }
+}
+
+impl Draw for OutlineText<'_> {
+    type T = u32;
+
+    fn draw(&self, canvas: &mut Canvas<u32, &mut [u32]>, x: i32, y: i32) {
+        let brush = Rgba {
+            space: self.space,
+            ..Rgba::from(self.color)
+        };
+        let mut baseline = y as f32 + self.cache.ascent();
+        for line in self.text.split('\n') {
+            let mut pen = x as f32;
+            for c in line.chars() {
+                let glyph = self.cache.glyph(c);
+                let left = math::round(pen as f64) as i32 + glyph.offset.0;
+                let top = math::round(baseline as f64) as i32 + glyph.offset.1;
+                for (gy, row) in glyph.coverage.chunks(glyph.width.max(1)).enumerate() {
+                    for (gx, &coverage) in row.iter().enumerate() {
+                        if coverage == 0 {
+                            continue;
+                        }
+                        let a = (coverage as u32 * brush.a as u32 + 127) / 255;
+                        Rgba {
+                            a: a as u8,
+                            ..brush
+                        }
+                        .draw(canvas, left + gx as i32, top + gy as i32);
+                    }
+                }
+                pen += glyph.advance;
+            }
+            baseline += self.cache.line_height();
+        }
+    }
+}
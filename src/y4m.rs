@@ -0,0 +1,140 @@
+use crate::{
+    sink::{write_sink, Batch},
+    Color, Image, PixelFormat, Sink,
+};
+
+/// Chroma subsampling of a YUV4MPEG2 stream.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Chroma {
+    /// One chroma sample per 2x2 pixels, the format most video encoders expect
+    #[default]
+    C420,
+    /// One chroma sample per pixel, without loss of color resolution
+    C444,
+}
+
+/// Converts a color to limited range BT.601 `[Y, Cb, Cr]`, the range players assume for YUV4MPEG2
+fn ycbcr(c: Color) -> [i32; 3] {
+    let (r, g, b) = (c.r as i32, c.g as i32, c.b as i32);
+    [
+        ((66 * r + 129 * g + 25 * b + 128) >> 8) + 16,
+        ((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128,
+        ((112 * r - 94 * g - 18 * b + 128) >> 8) + 128,
+    ]
+}
+
+/// Encodes finished frames, usually successive `Canvas` buffers, as a YUV4MPEG2 (`.y4m`) video stream that tools
+/// like ffmpeg read from a file or a pipe.
+///
+/// Frames are converted plane by plane while they are written, so encoding doesn't allocate.
+pub struct Y4mEncoder<S: Sink> {
+    sink: S,
+    size: (usize, usize),
+    frame_rate: (u32, u32),
+    chroma: Chroma,
+    header_written: bool,
+}
+
+impl<S: Sink> Y4mEncoder<S> {
+    /// Creates an encoder for frames of `size` pixels at 30 frames per second with 4:2:0 chroma
+    pub fn new(sink: S, size: (usize, usize)) -> Self {
+        Self {
+            sink,
+            size,
+            frame_rate: (30, 1),
+            chroma: Chroma::C420,
+            header_written: false,
+        }
+    }
+
+    /// Sets the frame rate to `numerator / denominator` frames per second, like 30000/1001 for NTSC rates
+    pub fn with_frame_rate(self, numerator: u32, denominator: u32) -> Self {
+        Self {
+            frame_rate: (numerator, denominator),
+            ..self
+        }
+    }
+
+    /// Sets the chroma subsampling, `Chroma::C420` by default
+    pub fn with_chroma(self, chroma: Chroma) -> Self {
+        Self { chroma, ..self }
+    }
+
+    fn write_header(&mut self) -> Result<(), S::Error> {
+        let ((w, h), (num, den)) = (self.size, self.frame_rate);
+        let chroma = match self.chroma {
+            Chroma::C420 => "420jpeg",
+            Chroma::C444 => "444",
+        };
+        write_sink!(
+            &mut self.sink,
+            "YUV4MPEG2 W{w} H{h} F{num}:{den} Ip A1:1 C{chroma}\n"
+        )?;
+        self.header_written = true;
+        Ok(())
+    }
+
+    /// Converts `frame` to YCbCr and appends it to the stream.
+    ///
+    /// Panics if the size of `frame` isn't the size of the encoder.
+    pub fn add_frame<P: PixelFormat, B: AsRef<[P]>>(
+        &mut self,
+        frame: &Image<P, B>,
+    ) -> Result<(), S::Error> {
+        assert_eq!(
+            frame.size(),
+            self.size,
+            "frame size doesn't match the video"
+        );
+        if !self.header_written {
+            self.write_header()?;
+        }
+        let (w, h) = self.size;
+        let sample = |x: usize, y: usize, channel: usize| {
+            ycbcr(frame.get(x, y).map_or(Color::BLACK, |p| p.to_color()))[channel]
+        };
+
+        self.sink.write_bytes(b"FRAME\n")?;
+        let mut out = Batch::new(&mut self.sink);
+        for y in 0..h {
+            for x in 0..w {
+                out.push(sample(x, y, 0) as u8)?;
+            }
+        }
+        for channel in 1..3 {
+            match self.chroma {
+                Chroma::C444 => {
+                    for y in 0..h {
+                        for x in 0..w {
+                            out.push(sample(x, y, channel) as u8)?;
+                        }
+                    }
+                }
+                Chroma::C420 => {
+                    // Averages the 2x2 block, or the part of it inside of the frame at odd sizes
+                    for y in (0..h).step_by(2) {
+                        for x in (0..w).step_by(2) {
+                            let (mut sum, mut count) = (0, 0);
+                            for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                                if x + dx < w && y + dy < h {
+                                    sum += sample(x + dx, y + dy, channel);
+                                    count += 1;
+                                }
+                            }
+                            out.push(((sum + count / 2) / count) as u8)?;
+                        }
+                    }
+                }
+            }
+        }
+        out.flush()
+    }
+
+    /// Writes the header if no frame was added and returns the sink
+    pub fn finish(mut self) -> Result<S, S::Error> {
+        if !self.header_written {
+            self.write_header()?;
+        }
+        Ok(self.sink)
+    }
+}
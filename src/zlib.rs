@@ -0,0 +1,595 @@
+//! zlib streams (RFC 1950) with DEFLATE compression (RFC 1951), as used by PNG.
+
+use alloc::{vec, vec::Vec};
+
+use crate::DecodeError;
+
+const WINDOW_SIZE: usize = 1 << 15;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// How many earlier positions with the same hash are tried when looking for a match
+const MAX_CHAIN: usize = 128;
+const HASH_BITS: u32 = 15;
+/// Number of tokens after which a new block with its own Huffman codes is started
+const BLOCK_TOKENS: usize = 1 << 15;
+const MAX_BITS: usize = 15;
+
+/// Base lengths of the length codes 257..=285, followed by the number of extra bits
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// Order in which the code lengths of the code length alphabet are stored
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+pub(crate) fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    // 5552 bytes is the most that can be summed before `b` could overflow
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    b << 16 | a
+}
+
+/// Compresses `data` into a zlib stream
+pub(crate) fn compress(data: &[u8]) -> Vec<u8> {
+    let mut out = BitWriter {
+        // Deflate with a 32K window and the default compression level
+        out: vec![0x78, 0x9c],
+        bits: 0,
+        len: 0,
+    };
+    let tokens = tokenize(data);
+    if tokens.is_empty() {
+        write_block(&mut out, &[], true);
+    }
+    let mut blocks = tokens.chunks(BLOCK_TOKENS).peekable();
+    while let Some(block) = blocks.next() {
+        write_block(&mut out, block, blocks.peek().is_none());
+    }
+    out.align();
+    out.out.extend_from_slice(&adler32(data).to_be_bytes());
+    out.out
+}
+
+/// Decompresses a zlib stream, checking its checksum.
+/// Streams that decompress to more than `max_len` bytes are rejected as corrupt before they are fully decompressed.
+pub(crate) fn decompress(data: &[u8], max_len: usize) -> Result<Vec<u8>, DecodeError> {
+    let [cmf, flg, ..] = *data else {
+        return Err(DecodeError::Truncated);
+    };
+    if cmf & 0x0f != 8 || cmf >> 4 > 7 || !(cmf as u16 * 256 + flg as u16).is_multiple_of(31) {
+        return Err(DecodeError::Corrupt);
+    }
+    if flg & 0x20 != 0 {
+        // Preset dictionaries aren't used by PNG
+        return Err(DecodeError::Unsupported);
+    }
+    let mut reader = BitReader {
+        data,
+        pos: 2,
+        bits: 0,
+        len: 0,
+    };
+    let out = inflate(&mut reader, max_len)?;
+    let checksum = data
+        .get(reader.pos..reader.pos + 4)
+        .ok_or(DecodeError::Truncated)?;
+    if checksum != adler32(&out).to_be_bytes() {
+        return Err(DecodeError::Corrupt);
+    }
+    Ok(out)
+}
+
+#[derive(Clone, Copy)]
+enum Token {
+    Literal(u8),
+    Match { len: u16, dist: u16 },
+}
+
+fn hash(data: &[u8]) -> usize {
+    let v = (data[0] as u32) << 16 | (data[1] as u32) << 8 | data[2] as u32;
+    (v.wrapping_mul(0x9e37_79b1) >> (32 - HASH_BITS)) as usize
+}
+
+/// Finds repeated strings with hash chains, lazily preferring a longer match at the next byte
+fn tokenize(data: &[u8]) -> Vec<Token> {
+    // Positions are stored plus one, so that 0 is the end of a chain
+    let mut head = vec![0u32; 1 << HASH_BITS];
+    let mut prev = vec![0u32; WINDOW_SIZE];
+    let insert = |pos: usize, head: &mut [u32], prev: &mut [u32]| {
+        if pos + MIN_MATCH <= data.len() {
+            let h = hash(&data[pos..]);
+            prev[pos % WINDOW_SIZE] = head[h];
+            head[h] = pos as u32 + 1;
+        }
+    };
+    let find = |pos: usize, head: &[u32], prev: &[u32]| -> (usize, usize) {
+        let max = (data.len() - pos).min(MAX_MATCH);
+        if max < MIN_MATCH {
+            return (0, 0);
+        }
+        let (mut best_len, mut best_dist) = (0, 0);
+        let mut candidate = head[hash(&data[pos..])] as usize;
+        for _ in 0..MAX_CHAIN {
+            if candidate == 0 || pos - (candidate - 1) > WINDOW_SIZE {
+                break;
+            }
+            let start = candidate - 1;
+            if data[start + best_len] == data[pos + best_len] {
+                let len = data[start..start + max]
+                    .iter()
+                    .zip(&data[pos..pos + max])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if len > best_len {
+                    (best_len, best_dist) = (len, pos - start);
+                    if len == max {
+                        break;
+                    }
+                }
+            }
+            let next = prev[start % WINDOW_SIZE] as usize;
+            // Older entries of the ring buffer were overwritten by newer positions
+            if next >= candidate {
+                break;
+            }
+            candidate = next;
+        }
+        if best_len >= MIN_MATCH {
+            (best_len, best_dist)
+        } else {
+            (0, 0)
+        }
+    };
+
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        let (len, dist) = find(pos, &head, &prev);
+        insert(pos, &mut head, &mut prev);
+        if len == 0 {
+            tokens.push(Token::Literal(data[pos]));
+            pos += 1;
+            continue;
+        }
+        if len < MAX_MATCH && pos + 1 < data.len() && find(pos + 1, &head, &prev).0 > len {
+            tokens.push(Token::Literal(data[pos]));
+            pos += 1;
+            continue;
+        }
+        tokens.push(Token::Match {
+            len: len as u16,
+            dist: dist as u16,
+        });
+        for p in pos + 1..pos + len {
+            insert(p, &mut head, &mut prev);
+        }
+        pos += len;
+    }
+    tokens
+}
+
+/// Returns the index of the code whose base is the largest one not above `value`
+fn code_index(bases: &[u16], value: u16) -> usize {
+    bases.partition_point(|&base| base <= value) - 1
+}
+
+/// Returns Huffman code lengths of at most `max_bits` for the given symbol frequencies
+fn code_lengths(freqs: &[u32], max_bits: u8) -> Vec<u8> {
+    let mut freqs = freqs.to_vec();
+    loop {
+        let mut lengths = vec![0u8; freqs.len()];
+        // (weight, node), leaves are the symbols and internal nodes follow
+        let mut queue: Vec<(u32, usize)> = freqs
+            .iter()
+            .enumerate()
+            .filter(|(_, &f)| f > 0)
+            .map(|(i, &f)| (f, i))
+            .collect();
+        match queue.len() {
+            0 => return lengths,
+            1 => {
+                // A lone code is padded with an unused one, decoders may reject incomplete codes
+                let symbol = queue[0].1;
+                lengths[symbol] = 1;
+                lengths[if symbol == 0 { 1 } else { 0 }] = 1;
+                return lengths;
+            }
+            _ => {}
+        }
+        let mut parent = vec![usize::MAX; freqs.len() + queue.len()];
+        let mut next = freqs.len();
+        while queue.len() > 1 {
+            queue.sort_unstable_by(|a, b| b.cmp(a));
+            let (wa, a) = queue.pop().unwrap();
+            let (wb, b) = queue.pop().unwrap();
+            parent[a] = next;
+            parent[b] = next;
+            queue.push((wa + wb, next));
+            next += 1;
+        }
+        let mut too_long = false;
+        for (symbol, length) in lengths.iter_mut().enumerate() {
+            if freqs[symbol] == 0 {
+                continue;
+            }
+            let (mut node, mut depth) = (symbol, 0u8);
+            while parent[node] != usize::MAX {
+                node = parent[node];
+                depth += 1;
+            }
+            *length = depth;
+            too_long |= depth > max_bits;
+        }
+        if !too_long {
+            return lengths;
+        }
+        // Flattening the distribution makes the tree shallower, repeat until it fits
+        for f in freqs.iter_mut().filter(|f| **f > 0) {
+            *f = f.div_ceil(2);
+        }
+    }
+}
+
+/// Returns the canonical codes for the code lengths, bit reversed for the LSB first bit stream
+fn canonical_codes(lengths: &[u8]) -> Vec<u16> {
+    let mut count = [0u32; MAX_BITS + 1];
+    for &l in lengths {
+        count[l as usize] += 1;
+    }
+    count[0] = 0;
+    let mut next = [0u32; MAX_BITS + 2];
+    for bits in 1..=MAX_BITS {
+        next[bits + 1] = (next[bits] + count[bits]) << 1;
+    }
+    lengths
+        .iter()
+        .map(|&l| {
+            if l == 0 {
+                return 0;
+            }
+            let code = next[l as usize];
+            next[l as usize] += 1;
+            (code as u16).reverse_bits() >> (16 - l)
+        })
+        .collect()
+}
+
+/// Run-length encodes code lengths with the symbols 16 (repeat previous), 17 and 18 (repeat zero)
+fn run_lengths(lengths: &[u8]) -> Vec<(u8, u8)> {
+    let mut symbols = Vec::new();
+    let mut i = 0;
+    while i < lengths.len() {
+        let l = lengths[i];
+        let run = lengths[i..].iter().take_while(|&&x| x == l).count();
+        if l == 0 && run >= 3 {
+            let run = run.min(138);
+            if run <= 10 {
+                symbols.push((17, run as u8 - 3));
+            } else {
+                symbols.push((18, run as u8 - 11));
+            }
+            i += run;
+        } else if l != 0 && run >= 4 {
+            symbols.push((l, 0));
+            let run = (run - 1).min(6);
+            symbols.push((16, run as u8 - 3));
+            i += run + 1;
+        } else {
+            symbols.push((l, 0));
+            i += 1;
+        }
+    }
+    symbols
+}
+
+struct BitWriter {
+    out: Vec<u8>,
+    bits: u64,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, len: u8) {
+        self.bits |= (value as u64) << self.len;
+        self.len += len as u32;
+        while self.len >= 8 {
+            self.out.push(self.bits as u8);
+            self.bits >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn align(&mut self) {
+        if self.len > 0 {
+            self.out.push(self.bits as u8);
+            (self.bits, self.len) = (0, 0);
+        }
+    }
+}
+
+/// Writes a block with dynamic Huffman codes
+fn write_block(out: &mut BitWriter, tokens: &[Token], last: bool) {
+    let mut litlen_freqs = [0u32; 286];
+    let mut dist_freqs = [0u32; 30];
+    litlen_freqs[256] = 1;
+    for token in tokens {
+        match *token {
+            Token::Literal(b) => litlen_freqs[b as usize] += 1,
+            Token::Match { len, dist } => {
+                litlen_freqs[257 + code_index(&LENGTH_BASE, len)] += 1;
+                dist_freqs[code_index(&DIST_BASE, dist)] += 1;
+            }
+        }
+    }
+    let litlen_lengths = code_lengths(&litlen_freqs, MAX_BITS as u8);
+    let mut dist_lengths = code_lengths(&dist_freqs, MAX_BITS as u8);
+    if dist_lengths.iter().all(|&l| l == 0) {
+        // At least one distance code has to be present
+        dist_lengths[..2].fill(1);
+    }
+    let hlit = 257.max(litlen_lengths.iter().rposition(|&l| l > 0).unwrap() + 1);
+    let hdist = dist_lengths.iter().rposition(|&l| l > 0).unwrap() + 1;
+
+    let mut all_lengths = litlen_lengths[..hlit].to_vec();
+    all_lengths.extend_from_slice(&dist_lengths[..hdist]);
+    let runs = run_lengths(&all_lengths);
+    let mut cl_freqs = [0u32; 19];
+    for &(symbol, _) in &runs {
+        cl_freqs[symbol as usize] += 1;
+    }
+    let cl_lengths = code_lengths(&cl_freqs, 7);
+    let cl_codes = canonical_codes(&cl_lengths);
+    let hclen = 4.max(
+        CODE_LENGTH_ORDER
+            .iter()
+            .rposition(|&i| cl_lengths[i] > 0)
+            .unwrap()
+            + 1,
+    );
+
+    out.write(last as u32, 1);
+    out.write(2, 2);
+    out.write(hlit as u32 - 257, 5);
+    out.write(hdist as u32 - 1, 5);
+    out.write(hclen as u32 - 4, 4);
+    for &i in &CODE_LENGTH_ORDER[..hclen] {
+        out.write(cl_lengths[i] as u32, 3);
+    }
+    for &(symbol, extra) in &runs {
+        let s = symbol as usize;
+        out.write(cl_codes[s] as u32, cl_lengths[s]);
+        match symbol {
+            16 => out.write(extra as u32, 2),
+            17 => out.write(extra as u32, 3),
+            18 => out.write(extra as u32, 7),
+            _ => {}
+        }
+    }
+
+    let litlen_codes = canonical_codes(&litlen_lengths);
+    let dist_codes = canonical_codes(&dist_lengths);
+    for token in tokens {
+        match *token {
+            Token::Literal(b) => {
+                out.write(litlen_codes[b as usize] as u32, litlen_lengths[b as usize]);
+            }
+            Token::Match { len, dist } => {
+                let i = code_index(&LENGTH_BASE, len);
+                out.write(litlen_codes[257 + i] as u32, litlen_lengths[257 + i]);
+                out.write((len - LENGTH_BASE[i]) as u32, LENGTH_EXTRA[i]);
+                let i = code_index(&DIST_BASE, dist);
+                out.write(dist_codes[i] as u32, dist_lengths[i]);
+                out.write((dist - DIST_BASE[i]) as u32, DIST_EXTRA[i]);
+            }
+        }
+    }
+    out.write(litlen_codes[256] as u32, litlen_lengths[256]);
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    bits: u32,
+    len: u32,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, len: u32) -> Result<u32, DecodeError> {
+        while self.len < len {
+            let byte = *self.data.get(self.pos).ok_or(DecodeError::Truncated)?;
+            self.bits |= (byte as u32) << self.len;
+            self.pos += 1;
+            self.len += 8;
+        }
+        let value = self.bits & ((1u64 << len) - 1) as u32;
+        self.bits >>= len;
+        self.len -= len;
+        Ok(value)
+    }
+
+    /// Skips to the next byte boundary
+    fn align(&mut self) {
+        self.bits = 0;
+        self.len = 0;
+    }
+}
+
+/// Canonical Huffman decoding table, the number of codes per length and the symbols ordered by code
+struct Huffman {
+    count: [u16; MAX_BITS + 1],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, DecodeError> {
+        let mut count = [0u16; MAX_BITS + 1];
+        for &l in lengths {
+            count[l as usize] += 1;
+        }
+        count[0] = 0;
+        let mut left = 1i32;
+        for &c in &count[1..] {
+            left = (left << 1) - c as i32;
+            if left < 0 {
+                return Err(DecodeError::Corrupt);
+            }
+        }
+        let mut offsets = [0u16; MAX_BITS + 2];
+        for bits in 1..=MAX_BITS {
+            offsets[bits + 1] = offsets[bits] + count[bits];
+        }
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, &l) in lengths.iter().enumerate() {
+            if l != 0 {
+                symbols[offsets[l as usize] as usize] = symbol as u16;
+                offsets[l as usize] += 1;
+            }
+        }
+        Ok(Self { count, symbols })
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, DecodeError> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for &count in &self.count[1..] {
+            code |= reader.read(1)? as i32;
+            let count = count as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(DecodeError::Corrupt)
+    }
+}
+
+fn fixed_codes() -> Result<(Huffman, Huffman), DecodeError> {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+    Ok((Huffman::new(&lengths)?, Huffman::new(&[5; 30])?))
+}
+
+fn dynamic_codes(reader: &mut BitReader) -> Result<(Huffman, Huffman), DecodeError> {
+    let hlit = reader.read(5)? as usize + 257;
+    let hdist = reader.read(5)? as usize + 1;
+    let hclen = reader.read(4)? as usize + 4;
+    let mut cl_lengths = [0u8; 19];
+    for &i in &CODE_LENGTH_ORDER[..hclen] {
+        cl_lengths[i] = reader.read(3)? as u8;
+    }
+    let cl = Huffman::new(&cl_lengths)?;
+    let mut lengths = Vec::with_capacity(hlit + hdist);
+    while lengths.len() < hlit + hdist {
+        let (value, repeat) = match cl.decode(reader)? {
+            symbol @ 0..=15 => (symbol as u8, 1),
+            16 => (
+                *lengths.last().ok_or(DecodeError::Corrupt)?,
+                3 + reader.read(2)?,
+            ),
+            17 => (0, 3 + reader.read(3)?),
+            _ => (0, 11 + reader.read(7)?),
+        };
+        lengths.extend((0..repeat).map(|_| value));
+    }
+    if lengths.len() > hlit + hdist || lengths[256] == 0 {
+        return Err(DecodeError::Corrupt);
+    }
+    Ok((
+        Huffman::new(&lengths[..hlit])?,
+        Huffman::new(&lengths[hlit..])?,
+    ))
+}
+
+fn inflate(reader: &mut BitReader, max_len: usize) -> Result<Vec<u8>, DecodeError> {
+    let mut out = Vec::new();
+    loop {
+        let last = reader.read(1)? == 1;
+        match reader.read(2)? {
+            0 => {
+                reader.align();
+                let header = reader
+                    .data
+                    .get(reader.pos..reader.pos + 4)
+                    .ok_or(DecodeError::Truncated)?;
+                let len = u16::from_le_bytes([header[0], header[1]]);
+                if len != !u16::from_le_bytes([header[2], header[3]]) {
+                    return Err(DecodeError::Corrupt);
+                }
+                let start = reader.pos + 4;
+                let stored = reader
+                    .data
+                    .get(start..start + len as usize)
+                    .ok_or(DecodeError::Truncated)?;
+                if out.len() + stored.len() > max_len {
+                    return Err(DecodeError::Corrupt);
+                }
+                out.extend_from_slice(stored);
+                reader.pos = start + len as usize;
+            }
+            kind @ (1 | 2) => {
+                let (litlen, dist) = if kind == 1 {
+                    fixed_codes()?
+                } else {
+                    dynamic_codes(reader)?
+                };
+                loop {
+                    let symbol = litlen.decode(reader)? as usize;
+                    match symbol {
+                        0..=255 if out.len() < max_len => out.push(symbol as u8),
+                        256 => break,
+                        257..=285 => {
+                            let i = symbol - 257;
+                            let len = LENGTH_BASE[i] as usize
+                                + reader.read(LENGTH_EXTRA[i] as u32)? as usize;
+                            let i = dist.decode(reader)? as usize;
+                            if i >= 30 {
+                                return Err(DecodeError::Corrupt);
+                            }
+                            let d =
+                                DIST_BASE[i] as usize + reader.read(DIST_EXTRA[i] as u32)? as usize;
+                            if d > out.len() || out.len() + len > max_len {
+                                return Err(DecodeError::Corrupt);
+                            }
+                            // The match can overlap the bytes it produces, so copy byte by byte
+                            let start = out.len() - d;
+                            for i in start..start + len {
+                                out.push(out[i]);
+                            }
+                        }
+                        _ => return Err(DecodeError::Corrupt),
+                    }
+                }
+            }
+            _ => return Err(DecodeError::Corrupt),
+        }
+        if last {
+            // The unused bits of the last byte are padding
+            reader.align();
+            return Ok(out);
+        }
+    }
+}
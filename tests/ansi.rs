@@ -0,0 +1,82 @@
+use framebrush::{AnsiColors, AnsiPresenter, FmtSink, Image};
+
+fn present(presenter: &mut AnsiPresenter, frame: &Image<u32, &[u32]>) -> (usize, String) {
+    let mut sink = FmtSink(String::new());
+    let cells = presenter.present(&mut sink, frame).unwrap();
+    (cells, sink.0)
+}
+
+#[test]
+fn half_blocks() {
+    let mut data = [
+        0xff0000, 0x00ff00, //
+        0xff0000, 0x0000ff, //
+        0xffffff, 0xffffff,
+    ];
+    let mut presenter = AnsiPresenter::new();
+    assert_eq!(
+        present(&mut presenter, &Image::new(&data[..], 2, 3)),
+        (
+            4,
+            "\x1b[1;1H\x1b[48;2;255;0;0m \x1b[38;2;0;255;0;48;2;0;0;255m▀\
+             \x1b[2;1H\x1b[38;2;255;255;255;49m▀▀\x1b[0m"
+                .into()
+        )
+    );
+
+    // Only the changed cell is written
+    assert_eq!(
+        present(&mut presenter, &Image::new(&data[..], 2, 3)),
+        (0, "".into())
+    );
+    data[3] = 0xff0000;
+    assert_eq!(
+        present(&mut presenter, &Image::new(&data[..], 2, 3)),
+        (1, "\x1b[1;2H\x1b[38;2;0;255;0;48;2;255;0;0m▀\x1b[0m".into())
+    );
+}
+
+#[test]
+fn lower_half_reuses_colors() {
+    let data = [0x102030, 0x405060, 0x405060, 0x102030];
+    let mut presenter = AnsiPresenter::new();
+    assert_eq!(
+        present(&mut presenter, &Image::new(&data[..], 2, 2)),
+        (
+            2,
+            "\x1b[1;1H\x1b[38;2;16;32;48;48;2;64;80;96m▀▄\x1b[0m".into()
+        )
+    );
+}
+
+#[test]
+fn indexed_colors() {
+    let data = [0xff0000, 0x808080];
+    let mut presenter = AnsiPresenter::new().with_colors(AnsiColors::Indexed);
+    assert_eq!(
+        present(&mut presenter, &Image::new(&data[..], 1, 2)),
+        (1, "\x1b[1;1H\x1b[38;5;196;48;5;244m▀\x1b[0m".into())
+    );
+
+    // Colors that map to the same palette index don't count as a change
+    let data = [0xfe0101, 0x818181];
+    assert_eq!(
+        present(&mut presenter, &Image::new(&data[..], 1, 2)),
+        (0, "".into())
+    );
+}
+
+#[test]
+fn redraw() {
+    let data = [0x000000];
+    let mut presenter = AnsiPresenter::new().with_origin(3, 1);
+    let frame = Image::new(&data[..], 1, 1);
+    let expected = (1, "\x1b[2;4H\x1b[38;2;0;0;0;49m▀\x1b[0m".into());
+    assert_eq!(present(&mut presenter, &frame), expected);
+    presenter.invalidate();
+    assert_eq!(present(&mut presenter, &frame), expected);
+
+    // A frame with another size is written completely
+    let data = [0x000000; 2];
+    assert_eq!(present(&mut presenter, &Image::new(&data[..], 1, 2)).0, 1);
+}
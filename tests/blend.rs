@@ -0,0 +1,44 @@
+use framebrush::{blend, linear_to_srgb, srgb_to_linear, BlendSpace, Canvas, Rgba, LINEAR_MAX};
+
+#[test]
+fn srgb_linear_round_trip() {
+    for c in 0..=255 {
+        assert_eq!(linear_to_srgb(srgb_to_linear(c)), c);
+    }
+    assert_eq!(srgb_to_linear(0), 0);
+    assert_eq!(srgb_to_linear(255), LINEAR_MAX);
+    // sRGB 188 is ~50% linear light
+    assert_eq!(srgb_to_linear(188), 2059);
+    assert_eq!(linear_to_srgb(2059), 188);
+}
+
+#[test]
+fn blend_white_over_black() {
+    assert_eq!(blend(0x000000, 0xffffff, 128, BlendSpace::Srgb), 0x808080);
+    assert_eq!(blend(0x000000, 0xffffff, 128, BlendSpace::Linear), 0xbcbcbc);
+}
+
+#[test]
+fn blend_red_over_green() {
+    assert_eq!(blend(0x00ff00, 0xff0000, 128, BlendSpace::Srgb), 0x807f00);
+    assert_eq!(blend(0x00ff00, 0xff0000, 128, BlendSpace::Linear), 0xbcbb00);
+}
+
+#[test]
+fn blend_extremes() {
+    for space in [BlendSpace::Srgb, BlendSpace::Linear] {
+        assert_eq!(blend(0x123456, 0xabcdef, 0, space), 0x123456);
+        assert_eq!(blend(0x123456, 0xabcdef, 255, space), 0xabcdef);
+        // The upper byte of the destination is preserved
+        assert_eq!(blend(0xff000000, 0x00ffffff, 255, space), 0xffffffff);
+    }
+}
+
+#[test]
+fn rgba_brush() {
+    let mut buf = [0u32; 4];
+    let mut canvas = Canvas::new(&mut buf[..], (2, 2), (2, 2));
+    canvas.rect(0, 0, 1, 2, &Rgba::new(255, 255, 255, 128));
+    canvas.rect(1, 0, 1, 2, &Rgba::new(255, 255, 255, 128).linear());
+    assert_eq!(buf, [0x808080, 0xbcbcbc, 0x808080, 0xbcbcbc]);
+}
@@ -0,0 +1,127 @@
+use framebrush::{BlitOptions, Canvas, Invert, RasterOp, Xor};
+
+#[test]
+fn raster_ops() {
+    assert_eq!(RasterOp::Copy.apply(0b1100u8, 0b1010), Some(0b1010));
+    assert_eq!(RasterOp::And.apply(0b1100u8, 0b1010), Some(0b1000));
+    assert_eq!(RasterOp::Or.apply(0b1100u8, 0b1010), Some(0b1110));
+    assert_eq!(RasterOp::Xor.apply(0b1100u8, 0b1010), Some(0b0110));
+    assert_eq!(RasterOp::ColorKey(5u8).apply(1, 5), None);
+    assert_eq!(RasterOp::ColorKey(5u8).apply(1, 6), Some(6));
+}
+
+#[test]
+fn blit() {
+    let mut src_buf = [1u32, 2, 3, 4, 5, 6];
+    let src = Canvas::new(&mut src_buf, (3, 2), (3, 2));
+
+    let mut buf = [0u32; 16];
+    let mut canvas = Canvas::new(&mut buf, (4, 4), (4, 4));
+    canvas.blit(&src, (1, 0, 2, 2), 1, 2);
+    assert_eq!(buf[8..], [0, 2, 3, 0, 0, 5, 6, 0]);
+
+    // Scaled to twice the width and flipped horizontally
+    let mut buf = [0u32; 12];
+    let mut canvas = Canvas::new(&mut buf, (6, 2), (6, 2));
+    let options = BlitOptions {
+        size: Some((6, 2)),
+        flip_h: true,
+        ..Default::default()
+    };
+    canvas.blit_with(&src, (0, 0, 3, 2), 0, 0, options);
+    assert_eq!(buf, [3, 3, 2, 2, 1, 1, 6, 6, 5, 5, 4, 4]);
+
+    // Flipped vertically, skipping the color key
+    let mut buf = [9u32; 6];
+    let mut canvas = Canvas::new(&mut buf, (3, 2), (3, 2));
+    let options = BlitOptions {
+        flip_v: true,
+        op: RasterOp::ColorKey(5),
+        ..Default::default()
+    };
+    canvas.blit_with(&src, (0, 0, 3, 2), 0, 0, options);
+    assert_eq!(buf, [4, 9, 6, 1, 2, 3]);
+
+    // Shrinking skips source pixels
+    let mut buf = [0u32; 1];
+    let mut canvas = Canvas::new(&mut buf, (1, 1), (1, 1));
+    let options = BlitOptions {
+        size: Some((1, 1)),
+        op: RasterOp::Or,
+        ..Default::default()
+    };
+    canvas.blit_with(&src, (1, 0, 2, 2), 0, 0, options);
+    assert_eq!(buf, [2]);
+}
+
+#[test]
+fn copy_within() {
+    let row =
+        |canvas: &Canvas<u32, &mut [u32; 5]>| (0..5).map(|x| *canvas.get(x, 0)).collect::<Vec<_>>();
+
+    let mut buf = [1u32, 2, 3, 4, 5];
+    let mut canvas = Canvas::new(&mut buf, (5, 1), (5, 1));
+    canvas.copy_within((0, 0, 3, 1), 2, 0);
+    assert_eq!(row(&canvas), [1, 2, 1, 2, 3]);
+    canvas.copy_within((2, 0, 3, 1), 1, 0);
+    assert_eq!(row(&canvas), [1, 1, 2, 3, 3]);
+
+    // Overlapping rows, moving down and left
+    let mut buf = [1u32, 2, 3, 4, 5, 6, 7, 8, 9];
+    let mut canvas = Canvas::new(&mut buf, (3, 3), (3, 3));
+    canvas.copy_within((1, 0, 2, 2), 0, 1);
+    assert_eq!(buf, [1, 2, 3, 2, 3, 6, 5, 6, 9]);
+
+    // Every pixel is combined with the source as it was before the copy
+    let mut buf = [1u32, 2, 4, 8];
+    let mut canvas = Canvas::new(&mut buf, (4, 1), (4, 1));
+    canvas.copy_within_with((0, 0, 3, 1), 1, 0, RasterOp::Or);
+    assert_eq!(buf, [1, 3, 6, 12]);
+    let mut canvas = Canvas::new(&mut buf, (4, 1), (4, 1));
+    canvas.copy_within_with((1, 0, 3, 1), 0, 0, RasterOp::Xor);
+    assert_eq!(buf, [2, 5, 10, 12]);
+    let mut canvas = Canvas::new(&mut buf, (4, 1), (4, 1));
+    canvas.copy_within_with((0, 0, 4, 1), 0, 0, RasterOp::ColorKey(5));
+    assert_eq!(buf, [2, 5, 10, 12]);
+}
+
+#[test]
+fn xor_and_invert() {
+    let mut buf = [0x123456u32, 0xffffff, 0, 0x00ff00];
+    let mut canvas = Canvas::new(&mut buf, (2, 2), (2, 2));
+    canvas.borrowed().rect(0, 0, 2, 1, &Xor(0xffffff));
+    canvas.borrowed().line(0, 1, 1, 1, &Invert::new());
+    assert_eq!(buf, [0xedcba9, 0, 0xffffffff, 0xffff00ff]);
+
+    // Upscaled, every surface pixel of the canvas pixel is inverted
+    let mut buf = [0u32; 4 * 4];
+    let mut canvas = Canvas::new(&mut buf, (4, 4), (2, 2));
+    canvas.borrowed().rect(1, 0, 1, 1, &Xor(7));
+    assert_eq!(buf[..8], [0, 0, 7, 7, 0, 0, 7, 7]);
+}
+
+#[test]
+fn xor_round_trip() {
+    // Unscaled, upscaled and downscaled canvases
+    for (surface, canvas_size) in [
+        ((16, 12), (16, 12)),
+        ((32, 24), (16, 12)),
+        ((11, 7), (16, 12)),
+    ] {
+        let original: Vec<u32> = (0..surface.0 * surface.1)
+            .map(|i| (i as u32).wrapping_mul(0x9e3779b9) & 0xffffff)
+            .collect();
+        let mut buf = original.clone();
+        let mut canvas = Canvas::new(&mut buf[..], surface, canvas_size);
+        let shapes = |canvas: &mut Canvas<u32, &mut [u32]>| {
+            canvas.borrowed().rect(2, 1, 9, 7, &Xor(0xffffff));
+            canvas.borrowed().line(0, 11, 15, 0, &Xor(0x00ff00));
+            canvas.borrowed().rect(5, 5, 4, 4, &Invert::new());
+        };
+
+        shapes(&mut canvas.borrowed());
+        assert_ne!(canvas.buf(), &original[..], "{surface:?}");
+        shapes(&mut canvas.borrowed());
+        assert_eq!(canvas.buf(), &original[..], "{surface:?}");
+    }
+}
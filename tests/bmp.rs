@@ -0,0 +1,129 @@
+use framebrush::{write_bmp, Bmp, BmpOptions, Color, DecodeError, Image};
+
+fn le32(out: &mut Vec<u8>, values: &[u32]) {
+    for v in values {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+}
+
+/// An indexed BMP with a 40 byte header, the given palette in RGB order and unpadded rows
+fn indexed(width: u32, height: i32, bits: u16, palette: &[[u8; 3]], rows: &[&[u8]]) -> Vec<u8> {
+    let offset = 14 + 40 + 4 * palette.len() as u32;
+    let mut data = b"BM".to_vec();
+    le32(&mut data, &[0, 0, offset, 40, width, height as u32]);
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&bits.to_le_bytes());
+    le32(&mut data, &[0, 0, 0, 0, palette.len() as u32, 0]);
+    for [r, g, b] in palette {
+        data.extend_from_slice(&[*b, *g, *r, 0]);
+    }
+    for row in rows {
+        data.extend_from_slice(row);
+        data.extend_from_slice(&[0; 3][..row.len().next_multiple_of(4) - row.len()]);
+    }
+    data
+}
+
+#[test]
+fn round_trip() {
+    let image = Image::new(
+        [
+            Color::rgba(255, 0, 0, 255),
+            Color::rgba(0, 255, 128, 64),
+            Color::rgba(1, 2, 3, 0),
+            Color::rgba(0, 0, 0, 255),
+            Color::rgba(255, 255, 255, 128),
+            Color::rgba(10, 20, 30, 40),
+        ],
+        3,
+        2,
+    );
+    for alpha in [false, true] {
+        for top_down in [false, true] {
+            let mut out = Vec::new();
+            write_bmp(&mut out, &image, BmpOptions { alpha, top_down }).unwrap();
+            let bmp = Bmp::parse(&out).unwrap();
+            assert_eq!(bmp.size(), (3, 2));
+            assert_eq!(bmp.is_top_down(), top_down);
+            assert_eq!(bmp.has_alpha(), alpha);
+            assert_eq!(bmp.bits_per_pixel(), if alpha { 32 } else { 24 });
+
+            let decoded = bmp.to_image::<Color>();
+            for (i, (a, b)) in image
+                .rows()
+                .flatten()
+                .zip(decoded.rows().flatten())
+                .enumerate()
+            {
+                let expected = if alpha { *a } else { Color { a: 255, ..*a } };
+                assert_eq!(
+                    *b, expected,
+                    "pixel {i}, alpha {alpha}, top down {top_down}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn layout() {
+    let image = Image::new([0x112233u32, 0x445566, 0x778899, 0xaabbcc], 1, 4);
+    let mut out = Vec::new();
+    write_bmp(&mut out, &image, BmpOptions::default()).unwrap();
+    // Rows of 3 bytes are padded to 4, the last row comes first
+    assert_eq!(out.len(), 14 + 40 + 4 * 4);
+    assert_eq!(&out[2..6], &(out.len() as u32).to_le_bytes());
+    assert_eq!(&out[54..58], &[0xcc, 0xbb, 0xaa, 0]);
+    assert_eq!(&out[66..70], &[0x33, 0x22, 0x11, 0]);
+
+    let mut buf = [0u32; 4];
+    Bmp::parse(&out).unwrap().decode_into(&mut buf);
+    assert_eq!(buf, [0x112233, 0x445566, 0x778899, 0xaabbcc]);
+}
+
+#[test]
+fn indexed_bits() {
+    let palette = [[0, 0, 0], [255, 255, 255], [255, 0, 0], [0, 0, 255]];
+    let color = |i: usize| Color::rgb(palette[i][0], palette[i][1], palette[i][2]);
+
+    // Bottom up, so the first stored row is the bottom one
+    let one = indexed(
+        10,
+        2,
+        1,
+        &palette[..2],
+        &[&[0b1000_0000, 0b0100_0000], &[0b0110_0000, 0]],
+    );
+    let bmp = Bmp::parse(&one).unwrap();
+    let top: Vec<_> = (0..10).map(|x| bmp.pixel(x, 0)).collect();
+    assert_eq!(top[..4], [color(0), color(1), color(1), color(0)]);
+    assert_eq!(bmp.pixel(9, 1), color(1));
+    assert_eq!(bmp.pixel(8, 1), color(0));
+
+    let four = indexed(3, -1, 4, &palette, &[&[0x23, 0x10]]);
+    let bmp = Bmp::parse(&four).unwrap();
+    assert!(bmp.is_top_down());
+    assert_eq!(
+        [bmp.pixel(0, 0), bmp.pixel(1, 0), bmp.pixel(2, 0)],
+        [color(2), color(3), color(1)]
+    );
+
+    let eight = indexed(2, 1, 8, &palette, &[&[3, 2]]);
+    let bmp = Bmp::parse(&eight).unwrap();
+    assert_eq!([bmp.pixel(0, 0), bmp.pixel(1, 0)], [color(3), color(2)]);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(Bmp::parse(b"GIF89a"), Err(DecodeError::InvalidMagic));
+    let mut out = Vec::new();
+    let image = Image::new([0u32; 4], 2, 2);
+    write_bmp(&mut out, &image, BmpOptions::default()).unwrap();
+    assert_eq!(
+        Bmp::parse(&out[..out.len() - 1]),
+        Err(DecodeError::Truncated)
+    );
+    // Run-length encoding
+    out[30] = 1;
+    assert_eq!(Bmp::parse(&out), Err(DecodeError::Unsupported));
+}
@@ -0,0 +1,57 @@
+use framebrush::{AnsiColors, BrailleRenderer, Color, Gray8, Image};
+
+#[test]
+fn patterns() {
+    let mut data = [Gray8(0); 4 * 4];
+    for y in 0..4 {
+        data[y * 4] = Gray8(255);
+    }
+    data[2] = Gray8(200);
+    data[3 + 3 * 4] = Gray8(100);
+    let image = Image::new(&data[..], 4, 4);
+
+    assert_eq!(BrailleRenderer::new().render(&image), "⡇⠁\n");
+    assert_eq!(
+        BrailleRenderer::new().with_threshold(100).render(&image),
+        "⡇⢁\n"
+    );
+    assert_eq!(
+        BrailleRenderer::new().with_invert(true).render(&image),
+        "⢸⣾\n"
+    );
+
+    // Partial cells at the right and bottom edges
+    let data = [Gray8(255); 3 * 5];
+    assert_eq!(
+        BrailleRenderer::new().render(&Image::new(&data[..], 3, 5)),
+        "⣿⡇\n⠉⠁\n"
+    );
+}
+
+#[test]
+fn colors() {
+    let (red, green) = (Color::rgb(255, 0, 0), Color::rgb(0, 200, 0));
+    let mut data = [Color::BLACK; 8 * 4];
+    for y in 0..4 {
+        data[y * 8..y * 8 + 4].fill(red);
+        data[y * 8 + 6] = green;
+    }
+    // Set dots with different colors are averaged
+    data[7] = Color::rgb(0, 100, 0);
+    let image = Image::new(&data[..], 8, 4);
+
+    let renderer = BrailleRenderer::new().with_threshold(1);
+    assert_eq!(
+        renderer
+            .with_colors(Some(AnsiColors::TrueColor))
+            .render(&image),
+        "\x1b[38;2;255;0;0m⣿⣿⠀\x1b[38;2;0;180;0m⡏\x1b[0m\n"
+    );
+
+    let mut out = String::new();
+    renderer
+        .with_colors(Some(AnsiColors::Indexed))
+        .write(&mut out, &image)
+        .unwrap();
+    assert_eq!(out, "\x1b[38;5;196m⣿⣿⠀\x1b[38;5;34m⡏\x1b[0m\n");
+}
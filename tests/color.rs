@@ -0,0 +1,135 @@
+use framebrush::{Color, ColorSpace, Gray4, Gray8, ParseColorError, PixelFormat, Rgb565};
+
+fn assert_close(actual: (f32, f32, f32), expected: (f32, f32, f32)) {
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+    assert!(
+        close(actual.0, expected.0) && close(actual.1, expected.1) && close(actual.2, expected.2),
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(Color::from_hex("#f80"), Ok(Color::rgb(0xff, 0x88, 0x00)));
+    assert_eq!(
+        Color::from_hex("#f808"),
+        Ok(Color::rgba(0xff, 0x88, 0, 0x88))
+    );
+    assert_eq!(Color::from_hex("12ab3C"), Ok(Color::rgb(0x12, 0xab, 0x3c)));
+    assert_eq!(
+        Color::from_hex("#12345678"),
+        Ok(Color::rgba(0x12, 0x34, 0x56, 0x78))
+    );
+    assert_eq!(
+        Color::from_hex("#12345"),
+        Err(ParseColorError::InvalidLength)
+    );
+    assert_eq!(Color::from_hex("#12g"), Err(ParseColorError::InvalidDigit));
+
+    assert_eq!(
+        Color::from_name("RebeccaPurple"),
+        Some(Color::rgb(0x66, 0x33, 0x99))
+    );
+    assert_eq!(Color::from_name("nope"), None);
+
+    // `parse` agrees with `from_hex` and `from_name`
+    assert_eq!("ff0000".parse(), Ok(Color::rgb(255, 0, 0)));
+    assert_eq!("#0000ff".parse(), Ok(Color::rgb(0, 0, 255)));
+    assert_eq!("navy".parse(), Ok(Color::rgb(0, 0, 0x80)));
+    assert_eq!("#12g".parse::<Color>(), Err(ParseColorError::InvalidDigit));
+    assert_eq!("nope".parse::<Color>(), Err(ParseColorError::UnknownName));
+}
+
+#[test]
+fn hsv_hsl() {
+    assert_eq!(Color::from_hsv(120., 1., 1.), Color::rgb(0, 255, 0));
+    assert_eq!(Color::from_hsv(-60., 1., 1.), Color::rgb(255, 0, 255));
+    assert_eq!(Color::from_hsv(0., 0., 0.5), Color::rgb(128, 128, 128));
+    assert_eq!(Color::from_hsl(240., 1., 0.5), Color::rgb(0, 0, 255));
+    assert_eq!(Color::from_hsl(0., 1., 0.75), Color::rgb(255, 128, 128));
+
+    assert_close(Color::rgb(255, 0, 0).to_hsl(), (0., 1., 0.5));
+    assert_close(Color::rgb(0, 128, 128).to_hsv(), (180., 1., 128. / 255.));
+    for c in [
+        Color::rgb(12, 200, 99),
+        Color::rgb(250, 3, 40),
+        Color::rgb(70, 70, 71),
+    ] {
+        let (h, s, v) = c.to_hsv();
+        assert_eq!(Color::from_hsv(h, s, v), c);
+        let (h, s, l) = c.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l), c);
+    }
+}
+
+#[test]
+fn linear_and_oklab() {
+    let (r, _, _) = Color::rgb(128, 0, 0).to_linear();
+    assert!((r - 0.21586).abs() < 1e-4);
+    assert_eq!(Color::from_linear(0.5, 0., 1.), Color::rgb(188, 0, 255));
+
+    assert_close(
+        Color::rgb(255, 0, 0).to_oklab(),
+        (0.62796, 0.22486, 0.12585),
+    );
+    assert_close(Color::WHITE.to_oklab(), (1., 0., 0.));
+    for c in [
+        Color::rgb(255, 0, 0),
+        Color::rgb(12, 200, 99),
+        Color::rgb(1, 2, 3),
+    ] {
+        let (l, a, b) = c.to_oklab();
+        assert_eq!(Color::from_oklab(l, a, b), c);
+    }
+}
+
+#[test]
+fn lerp() {
+    let (black, white) = (Color::BLACK, Color::WHITE);
+    assert_eq!(
+        black.lerp(white, 0.5, ColorSpace::Srgb),
+        Color::rgb(128, 128, 128)
+    );
+    assert_eq!(
+        black.lerp(white, 0.5, ColorSpace::Linear),
+        Color::rgb(188, 188, 188)
+    );
+    assert_eq!(black.lerp(white, 2., ColorSpace::Oklab), white);
+
+    // Hue takes the shorter way from red over magenta to blue
+    let (red, blue) = (Color::rgb(255, 0, 0), Color::rgb(0, 0, 255));
+    assert_eq!(
+        red.lerp(blue, 0.5, ColorSpace::Hsv),
+        Color::rgb(255, 0, 255)
+    );
+    // Gray keeps the hue of the other color instead of passing through red
+    let gray = Color::rgb(128, 128, 128);
+    assert_eq!(
+        gray.lerp(blue, 0.5, ColorSpace::Hsl).to_hsl().0.round(),
+        240.
+    );
+
+    let a = Color::rgba(0, 0, 0, 0).lerp(Color::rgba(0, 0, 0, 255), 0.25, ColorSpace::Srgb);
+    assert_eq!(a.a, 64);
+}
+
+#[test]
+fn lighten_darken() {
+    let red = Color::rgba(255, 0, 0, 100);
+    assert_eq!(red.lighten(0.25), Color::rgba(255, 128, 128, 100));
+    assert_eq!(red.darken(0.25), Color::rgba(128, 0, 0, 100));
+    assert_eq!(red.lighten(1.), Color::rgba(255, 255, 255, 100));
+}
+
+#[test]
+fn pixel_formats() {
+    let c = Color::rgb(255, 128, 0);
+    assert_eq!(c.to_pixel::<u32>(), 0xff8000);
+    assert_eq!(c.to_pixel::<Rgb565>(), Rgb565(0xfc00));
+    assert_eq!(Rgb565(0xfc00).to_color(), Color::rgb(255, 130, 0));
+    assert_eq!(c.to_pixel::<Gray8>(), Gray8(c.luma()));
+    assert_eq!(Color::WHITE.to_pixel::<Gray4>(), Gray4(15));
+    assert!(Color::WHITE.to_pixel::<bool>());
+    assert!(!Color::rgb(127, 127, 127).to_pixel::<bool>());
+    assert_eq!(Color::from_u32(0xff123456), Color::rgb(0x12, 0x34, 0x56));
+}
@@ -0,0 +1,90 @@
+use framebrush::{diffuse, Bayer, Canvas, Color, Diffusion, Gray4, Ordered};
+
+#[test]
+fn bayer_matrices() {
+    assert_eq!(
+        [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(x, y)| Bayer::X2.threshold(x, y)),
+        [0, 2, 3, 1]
+    );
+    for matrix in [Bayer::X2, Bayer::X4, Bayer::X8] {
+        let n = matrix.size() as i32;
+        let mut seen = vec![false; (n * n) as usize];
+        for y in 0..n {
+            for x in 0..n {
+                seen[matrix.threshold(x, y) as usize] = true;
+                // The pattern repeats in both directions
+                assert_eq!(matrix.threshold(x - n, y + n), matrix.threshold(x, y));
+            }
+        }
+        assert!(seen.into_iter().all(|s| s));
+    }
+}
+
+#[test]
+fn ordered_keeps_exact_colors() {
+    for c in [Color::WHITE, Color::BLACK, Color::rgb(12, 34, 56)] {
+        let brush = Ordered::<Color, u32>::new(c, Bayer::X8);
+        for y in 0..8 {
+            for x in 0..8 {
+                assert_eq!(brush.pixel(x, y), c.to_u32());
+            }
+        }
+    }
+    // Levels of a 4-bit target are multiples of 17
+    let brush = Ordered::<Color, Gray4>::new(Color::rgb(170, 170, 170), Bayer::X4);
+    assert!((0..16).all(|i| brush.pixel(i % 4, i / 4) == Gray4(10)));
+}
+
+#[test]
+fn ordered_preserves_average() {
+    // A quarter gray on a 1-bit canvas sets about a quarter of the pixels
+    let brush = Ordered::<Color, bool>::new(Color::rgb(64, 64, 64), Bayer::X8);
+    let mut buf = [false; 64];
+    let mut canvas = Canvas::new(&mut buf, (8, 8), (8, 8));
+    canvas.borrowed().rect(0, 0, 8, 8, &brush);
+    assert_eq!(buf.iter().filter(|&&p| p).count(), 16);
+
+    // Between two 4-bit levels, the pattern mixes just those two
+    let brush = Ordered::<Color, Gray4>::new(Color::rgb(178, 178, 178), Bayer::X4);
+    let levels: Vec<u8> = (0..16).map(|i| brush.pixel(i % 4, i / 4).0).collect();
+    assert!(levels.iter().all(|&l| l == 10 || l == 11));
+    assert_eq!(levels.iter().filter(|&&l| l == 11).count(), 8);
+}
+
+#[test]
+fn error_diffusion() {
+    for kernel in [Diffusion::FloydSteinberg, Diffusion::Atkinson] {
+        let mut errors = [[0; 3]; Diffusion::scratch_len(16)];
+
+        // Exact colors produce no error to spread
+        let mut buf = [0u32; 16 * 4];
+        let mut canvas = Canvas::new(&mut buf, (16, 4), (16, 4));
+        let source = |x: i32, _| Color::rgb(x as u8 * 16, 7, 200);
+        diffuse(
+            &mut canvas.borrowed(),
+            0,
+            0,
+            (16, 4),
+            &source,
+            kernel,
+            &mut errors,
+        );
+        assert!((0..64).all(|i| buf[i] == source(i as i32 % 16, 0).to_u32()));
+
+        let mut buf = [false; 16 * 16];
+        let mut canvas = Canvas::new(&mut buf, (16, 16), (16, 16));
+        let mut errors = [[0; 3]; Diffusion::scratch_len(16)];
+        let gray = Color::rgb(128, 128, 128);
+        diffuse(
+            &mut canvas.borrowed(),
+            0,
+            0,
+            (16, 16),
+            &gray,
+            kernel,
+            &mut errors,
+        );
+        let white = buf.iter().filter(|&&p| p).count();
+        assert!((110..=146).contains(&white), "{kernel:?}: {white}");
+    }
+}
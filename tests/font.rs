@@ -0,0 +1,254 @@
+use framebrush::{
+    BdfFont, Canvas, Draw, Font, FontError, Glyph, PsfFont, Text, FONT_5X7, FONT_8X8,
+};
+
+/// Renders a glyph as rows of `#` and `.`
+fn rows(glyph: &Glyph) -> Vec<String> {
+    (0..glyph.height)
+        .map(|y| {
+            (0..glyph.width)
+                .map(|x| if glyph.pixel(x, y) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+/// A 4x3 PSF2 font with two glyphs, a box for 'a' and 'ä' and a dot for '€'
+fn psf2() -> Vec<u8> {
+    let mut data = vec![0x72, 0xb5, 0x4a, 0x86];
+    for field in [0u32, 32, 1, 2, 3, 3, 4] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(&[0xf0, 0x90, 0xf0]);
+    data.extend_from_slice(&[0x00, 0x40, 0x00]);
+    data.extend_from_slice(b"a\xc3\xa4\xfe\x61\xcc\x88\xff");
+    data.extend_from_slice("€".as_bytes());
+    data.push(0xff);
+    data
+}
+
+#[test]
+fn builtin_fonts() {
+    for font in [FONT_5X7, FONT_8X8] {
+        assert!((' '..='~').all(|c| font.glyph(c).is_some()));
+        assert!(font.glyph('\u{7f}').is_none());
+        assert!(font.glyph('\n').is_none());
+        assert_eq!(font.line_height(), 8);
+    }
+    assert_eq!(
+        rows(&FONT_5X7.glyph('A').unwrap()),
+        [".###..", "#...#.", "#...#.", "#...#.", "#####.", "#...#.", "#...#.", "......",]
+    );
+    // Characters outside of ASCII fall back to '?'
+    assert_eq!(
+        rows(&FONT_5X7.glyph_or_fallback('é').unwrap()),
+        rows(&FONT_5X7.glyph('?').unwrap())
+    );
+}
+
+#[test]
+fn psf2_unicode_lookup() {
+    let data = psf2();
+    let font = PsfFont::parse(&data).unwrap();
+    assert_eq!(font.glyph_count(), 2);
+    assert_eq!(font.glyph_size(), (4, 3));
+    assert_eq!(font.glyph_index('a'), Some(0));
+    assert_eq!(font.glyph_index('ä'), Some(0));
+    assert_eq!(font.glyph_index('€'), Some(1));
+    // U+0308 only appears inside a sequence
+    assert_eq!(font.glyph_index('\u{308}'), None);
+    assert_eq!(rows(&font.glyph('a').unwrap()), ["####", "#..#", "####"]);
+    assert_eq!(rows(&font.glyph('€').unwrap()), ["....", ".#..", "...."]);
+}
+
+#[test]
+fn psf1_unicode_lookup() {
+    let mut data = vec![0x36, 0x04, 0x02, 2];
+    for i in 0..256u16 {
+        data.extend_from_slice(&[i as u8, !(i as u8)]);
+    }
+    for i in 0..256u16 {
+        // Glyph 65 also represents U+0391 GREEK CAPITAL LETTER ALPHA
+        data.extend_from_slice(&i.to_le_bytes());
+        if i == 65 {
+            data.extend_from_slice(&0x391u16.to_le_bytes());
+        }
+        data.extend_from_slice(&0xffffu16.to_le_bytes());
+    }
+    let font = PsfFont::parse(&data).unwrap();
+    assert_eq!(font.glyph_size(), (8, 2));
+    assert_eq!(font.glyph_index('A'), Some(65));
+    assert_eq!(font.glyph_index('Α'), Some(65));
+    assert_eq!(font.glyph_index('€'), None);
+    assert_eq!(rows(&font.glyph('Α').unwrap()), [".#.....#", "#.#####."]);
+}
+
+#[test]
+fn psf_errors() {
+    assert_eq!(PsfFont::parse(b"BM"), Err(FontError::InvalidMagic));
+    assert_eq!(
+        PsfFont::parse(&[0x36, 0x04, 0, 8, 0]),
+        Err(FontError::Truncated)
+    );
+    let mut data = psf2();
+    data.truncate(36);
+    assert_eq!(PsfFont::parse(&data), Err(FontError::Truncated));
+}
+
+const BDF: &str = "STARTFONT 2.1
+FONT -test-fixed
+SIZE 6 75 75
+FONTBOUNDINGBOX 6 8 0 -2
+STARTPROPERTIES 3
+FONT_ASCENT 6
+FONT_DESCENT 2
+DEFAULT_CHAR 0
+ENDPROPERTIES
+CHARS 3
+STARTCHAR defaultchar
+ENCODING 0
+DWIDTH 6 0
+BBX 4 4 1 0
+BITMAP
+F0
+90
+90
+F0
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+DWIDTH 6 0
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+STARTCHAR g
+ENCODING 103
+DWIDTH 5 0
+BBX 3 5 1 -2
+BITMAP
+E0
+A0
+E0
+20
+C0
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn bdf_glyphs() {
+    let font = BdfFont::parse(BDF.as_bytes()).unwrap();
+    assert_eq!(
+        (font.ascent(), font.descent(), font.line_height()),
+        (6, 2, 8)
+    );
+    assert_eq!(font.glyphs().count(), 2);
+
+    let g = font.glyph('g').unwrap();
+    assert_eq!((g.width, g.height, g.offset, g.advance), (3, 5, (1, 3), 5));
+    assert_eq!(rows(&g), ["###", "#.#", "###", "..#", "##."]);
+
+    // Missing characters fall back to DEFAULT_CHAR
+    let fallback = font.glyph_or_fallback('x').unwrap();
+    assert_eq!(rows(&fallback), ["####", "#..#", "#..#", "####"]);
+    assert_eq!(fallback.offset, (1, 2));
+
+    assert_eq!(BdfFont::parse(b"STARTCHAR"), Err(FontError::InvalidMagic));
+    assert_eq!(
+        BdfFont::parse(b"STARTFONT 2.1\nENDFONT\n"),
+        Err(FontError::InvalidHeader)
+    );
+}
+
+struct Mark;
+
+impl Draw for Mark {
+    type T = char;
+    fn draw(&self, canvas: &mut Canvas<char, &mut [char]>, x: i32, y: i32) {
+        canvas.put(x, y, '#');
+    }
+}
+
+fn render<F: Font>(text: &Text<F, Mark>, size: (usize, usize)) -> Vec<String> {
+    let mut buf = vec!['.'; size.0 * size.1];
+    Canvas::new(&mut buf, size, size)
+        .borrowed()
+        .draw(0, 0, text);
+    buf.chunks(size.0).map(|row| row.iter().collect()).collect()
+}
+
+#[test]
+fn text_with_loaded_fonts() {
+    let data = psf2();
+    let psf = PsfFont::parse(&data).unwrap();
+    assert_eq!(
+        render(&Text::new("a€\na", &psf, &Mark).with_spacing(1, 1), (9, 7)),
+        [
+            "####.....",
+            "#..#..#..",
+            "####.....",
+            ".........",
+            "####.....",
+            "#..#.....",
+            "####.....",
+        ]
+    );
+
+    let bdf = BdfFont::parse(BDF.as_bytes()).unwrap();
+    assert_eq!(
+        render(&Text::new("gg", &bdf, &Mark), (10, 8)),
+        [
+            "..........",
+            "..........",
+            "..........",
+            ".###..###.",
+            ".#.#..#.#.",
+            ".###..###.",
+            "...#....#.",
+            ".##...##..",
+        ]
+    );
+}
+
+#[test]
+fn builtin_font_scale() {
+    assert_eq!(
+        render(&Text::new("-", &FONT_8X8, &Mark).with_scale(2), (16, 8)),
+        [
+            "................",
+            "................",
+            "................",
+            "................",
+            "................",
+            "................",
+            "############....",
+            "############....",
+        ]
+    );
+}
+
+#[test]
+fn builtin_font_lines() {
+    // Negative spacing moves the glyphs into the empty column and row of their 6x8 cells
+    assert_eq!(
+        render(
+            &Text::new("TI\n:", &FONT_5X7, &Mark).with_spacing(-1, -2),
+            (11, 12)
+        ),
+        [
+            "#####.###..",
+            "..#....#...",
+            "..#....#...",
+            "..#....#...",
+            "..#....#...",
+            "..#....#...",
+            "..#...###..",
+            ".##........",
+            ".##........",
+            "...........",
+            ".##........",
+            ".##........",
+        ]
+    );
+}
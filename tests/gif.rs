@@ -0,0 +1,218 @@
+use framebrush::{Color, GifEncoder, Image, Palette};
+
+/// A frame read back by `decode`, with its rectangle as (x, y, w, h) and its palette already applied
+struct Frame {
+    rect: (usize, usize, usize, usize),
+    delay: u16,
+    pixels: Vec<Color>,
+}
+
+fn lzw_decode(data: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1 << min_code_size;
+    let reset = || -> Vec<Vec<u8>> { (0..clear + 2).map(|i| vec![i as u8]).collect() };
+    let (mut dict, mut size, mut prev) = (reset(), min_code_size + 1, None::<Vec<u8>>);
+    let (mut bits, mut len, mut pos, mut out) = (0u32, 0, 0, Vec::new());
+    loop {
+        while len < size {
+            bits |= (data[pos] as u32) << len;
+            pos += 1;
+            len += 8;
+        }
+        let code = (bits & ((1 << size) - 1)) as usize;
+        bits >>= size;
+        len -= size;
+        if code == clear {
+            (dict, size, prev) = (reset(), min_code_size + 1, None);
+            continue;
+        }
+        if code == clear + 1 {
+            return out;
+        }
+        let entry = match &prev {
+            _ if code < dict.len() => dict[code].clone(),
+            Some(p) if code == dict.len() => [&p[..], &p[..1]].concat(),
+            _ => panic!("invalid code {code}"),
+        };
+        out.extend_from_slice(&entry);
+        if let Some(p) = prev {
+            if dict.len() < 4096 {
+                dict.push([&p[..], &entry[..1]].concat());
+            }
+        }
+        if dict.len() == 1 << size && size < 12 {
+            size += 1;
+        }
+        prev = Some(entry);
+    }
+}
+
+/// Reads the size, the loop count and the frames of a GIF written by `GifEncoder`
+fn decode(data: &[u8]) -> ((usize, usize), Option<u16>, Vec<Frame>) {
+    assert_eq!(&data[..6], b"GIF89a");
+    let u16_at = |i: usize| u16::from_le_bytes([data[i], data[i + 1]]);
+    let size = (u16_at(6) as usize, u16_at(8) as usize);
+    let table = |i: usize, flags: u8| -> Vec<Color> {
+        let len = 2 << (flags & 7);
+        data[i..i + 3 * len]
+            .chunks(3)
+            .map(|c| Color::rgb(c[0], c[1], c[2]))
+            .collect()
+    };
+    let mut pos = 13;
+    let global = if data[10] & 0x80 != 0 {
+        let t = table(13, data[10]);
+        pos += 3 * t.len();
+        t
+    } else {
+        Vec::new()
+    };
+    let (mut loop_count, mut delay, mut frames) = (None, 0, Vec::new());
+    loop {
+        match data[pos] {
+            0x21 if data[pos + 1] == 0xff => {
+                assert_eq!(&data[pos + 3..pos + 14], b"NETSCAPE2.0");
+                loop_count = Some(u16_at(pos + 16));
+                pos += 19;
+            }
+            0x21 => {
+                delay = u16_at(pos + 4);
+                pos += 8;
+            }
+            0x2c => {
+                let rect = (
+                    u16_at(pos + 1) as usize,
+                    u16_at(pos + 3) as usize,
+                    u16_at(pos + 5) as usize,
+                    u16_at(pos + 7) as usize,
+                );
+                let flags = data[pos + 9];
+                pos += 10;
+                let palette = if flags & 0x80 != 0 {
+                    let t = table(pos, flags);
+                    pos += 3 * t.len();
+                    t
+                } else {
+                    global.clone()
+                };
+                let min_code_size = data[pos] as u32;
+                pos += 1;
+                let mut compressed = Vec::new();
+                while data[pos] != 0 {
+                    let len = data[pos] as usize;
+                    compressed.extend_from_slice(&data[pos + 1..pos + 1 + len]);
+                    pos += 1 + len;
+                }
+                pos += 1;
+                let indices = lzw_decode(&compressed, min_code_size);
+                assert_eq!(indices.len(), rect.2 * rect.3);
+                let pixels = indices.iter().map(|&i| palette[i as usize]).collect();
+                frames.push(Frame {
+                    rect,
+                    delay,
+                    pixels,
+                });
+            }
+            0x3b => return (size, loop_count, frames),
+            b => panic!("unexpected block {b:#x}"),
+        }
+    }
+}
+
+#[test]
+fn diff_frames() {
+    let (w, h) = (40, 30);
+    let first: Vec<u32> = (0..w * h)
+        .map(|i| (i % 7 * 0x202020 + i / w % 5 * 0x30) as u32)
+        .collect();
+    let mut second = first.clone();
+    for y in 20..23 {
+        for x in 10..15 {
+            second[x + y * w] = 0xff0000;
+        }
+    }
+
+    let mut gif = GifEncoder::new(Vec::new(), (w, h))
+        .with_diff(true)
+        .with_loop_count(Some(3));
+    for (frame, delay) in [(&first, 10), (&second, 20), (&second, 30)] {
+        gif.add_frame(&Image::new(&frame[..], w, h), delay).unwrap();
+    }
+    let (size, loop_count, frames) = decode(&gif.finish().unwrap());
+    assert_eq!((size, loop_count), ((w, h), Some(3)));
+    assert_eq!(frames.len(), 3);
+
+    assert_eq!((frames[0].rect, frames[0].delay), ((0, 0, w, h), 10));
+    assert!(frames[0]
+        .pixels
+        .iter()
+        .map(|c| c.to_u32())
+        .eq(first.iter().copied()));
+    assert_eq!((frames[1].rect, frames[1].delay), ((10, 20, 5, 3), 20));
+    assert!(frames[1].pixels.iter().all(|&c| c == Color::rgb(255, 0, 0)));
+    // Nothing changed, a single pixel carries the delay
+    assert_eq!((frames[2].rect, frames[2].delay), ((0, 0, 1, 1), 30));
+}
+
+#[test]
+fn empty_frames() {
+    for size in [(0, 0), (3, 0)] {
+        let mut gif = GifEncoder::new(Vec::new(), size).with_diff(true);
+        let frame = Image::new(&[0u32; 0][..], size.0, size.1);
+        gif.add_frame(&frame, 10).unwrap();
+        gif.add_frame(&frame, 20).unwrap();
+        let (decoded_size, _, frames) = decode(&gif.finish().unwrap());
+        assert_eq!(decoded_size, size);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            (frames[1].rect, frames[1].delay),
+            ((0, 0, size.0.min(1), 0), 20)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "GIF size is larger than 65535")]
+fn oversized() {
+    GifEncoder::new(Vec::new(), (65536, 1));
+}
+
+#[test]
+fn fixed_palette() {
+    // Noise needs more than 4096 LZW codes, so the dictionary is cleared on the way
+    let (w, h) = (120, 100);
+    let gray: Vec<Color> = (0..=255).map(|v| Color::rgb(v, v, v)).collect();
+    let pixels: Vec<Color> = (0..w * h as u32)
+        .map(|i| {
+            let v = (i.wrapping_mul(2_654_435_761) >> 24) as u8;
+            Color::rgb(v, v, v)
+        })
+        .collect();
+    let mut gif = GifEncoder::new(Vec::new(), (w as usize, h as usize))
+        .with_palette(Palette::new(gray))
+        .with_loop_count(None);
+    gif.add_frame(&Image::new(&pixels[..], w as usize, h as usize), 5)
+        .unwrap();
+    let data = gif.finish().unwrap();
+    // Global color table with 256 entries
+    assert_eq!(data[10], 0xf7);
+    let (_, loop_count, frames) = decode(&data);
+    assert_eq!(loop_count, None);
+    assert_eq!(frames[0].pixels, pixels);
+}
+
+#[test]
+fn median_cut() {
+    let colors = [
+        Color::rgb(250, 0, 0),
+        Color::rgb(240, 10, 0),
+        Color::rgb(0, 0, 250),
+        Color::rgb(0, 0, 240),
+    ];
+    let palette = Palette::median_cut(colors.iter().cycle().take(40).copied(), 2);
+    let mut result = palette.colors.clone();
+    result.sort_by_key(|c| c.to_u32());
+    assert_eq!(result, [Color::rgb(0, 0, 245), Color::rgb(245, 5, 0)]);
+
+    // Few enough colors are kept as they are
+    assert_eq!(Palette::median_cut(colors, 8).len(), 4);
+}
@@ -0,0 +1,94 @@
+use framebrush::{Canvas, Color, ColorSpace, Extend, Gradient, Shade, Stop};
+
+const RED: Color = Color::rgb(255, 0, 0);
+const BLUE: Color = Color::rgb(0, 0, 255);
+const STOPS: [Stop; 2] = [Stop::new(0., RED), Stop::new(1., BLUE)];
+
+#[test]
+fn stops() {
+    let gradient = Gradient::linear(0., 0., 10., 0., &STOPS);
+    assert_eq!(gradient.color_at(-1.), RED);
+    assert_eq!(gradient.color_at(0.5), Color::rgb(127, 0, 128));
+    assert_eq!(gradient.color_at(2.), BLUE);
+    assert_eq!(
+        gradient.with_space(ColorSpace::Linear).color_at(0.5),
+        Color::rgb(187, 0, 188)
+    );
+
+    let three = [
+        Stop::new(0., RED),
+        Stop::new(0.25, Color::WHITE),
+        Stop::new(1., BLUE),
+    ];
+    let gradient = Gradient::linear(0., 0., 10., 0., &three);
+    assert_eq!(gradient.color_at(0.25), Color::WHITE);
+    assert_eq!(gradient.color_at(0.625), Color::rgb(127, 127, 255));
+
+    assert_eq!(
+        Gradient::linear(0., 0., 1., 0., &[]).color_at(0.5),
+        Color::TRANSPARENT
+    );
+    assert_eq!(
+        Gradient::linear(0., 0., 1., 0., &STOPS[1..]).color_at(0.),
+        BLUE
+    );
+}
+
+#[test]
+fn extend() {
+    let gradient = Gradient::linear(0., 0., 10., 0., &STOPS);
+    let at = |extend, t| gradient.with_extend(extend).color_at(t);
+    assert_eq!(at(Extend::Pad, 1.25), BLUE);
+    assert_eq!(at(Extend::Repeat, 1.25), at(Extend::Pad, 0.25));
+    assert_eq!(at(Extend::Repeat, -0.75), at(Extend::Pad, 0.25));
+    assert_eq!(at(Extend::Reflect, 1.25), at(Extend::Pad, 0.75));
+    assert_eq!(at(Extend::Reflect, -0.25), at(Extend::Pad, 0.25));
+}
+
+#[test]
+fn geometry() {
+    // Offsets are sampled at pixel centers
+    let linear = Gradient::linear(0., 0., 0., 10., &STOPS);
+    assert_eq!(linear.offset(3, 4), 0.45);
+    assert_eq!(Gradient::linear(2., 2., 2., 2., &STOPS).offset(5, 5), 0.);
+
+    let radial = Gradient::radial(0.5, 0.5, 4., &STOPS);
+    assert_eq!(radial.offset(0, 0), 0.);
+    assert_eq!(radial.offset(3, 0), 0.75);
+
+    let conic = Gradient::conic(0.5, 0.5, 0., &STOPS);
+    assert!((conic.offset(5, 0) - 0.).abs() < 1e-6);
+    assert!((conic.offset(0, 5) - 0.25).abs() < 1e-6);
+    assert!((conic.offset(-5, 0) - 0.5).abs() < 1e-6);
+    assert!((conic.offset(0, -5) - 0.75).abs() < 1e-6);
+}
+
+#[test]
+fn degenerate() {
+    // A zero radius puts every pixel at the end, a NaN radius at the start
+    assert_eq!(Gradient::radial(0., 0., 0., &STOPS).shade(3, 3), BLUE);
+    let nan = Gradient::radial(0., 0., f32::NAN, &STOPS);
+    for extend in [Extend::Pad, Extend::Repeat, Extend::Reflect] {
+        assert_eq!(nan.with_extend(extend).shade(3, 3), RED);
+        assert_eq!(nan.with_extend(extend).color_at(f32::NAN), RED);
+    }
+
+    let nan_stops = [Stop::new(f32::NAN, RED), Stop::new(1., BLUE)];
+    let gradient = Gradient::linear(0., 0., 1., 0., &nan_stops);
+    assert_eq!(gradient.color_at(1.), BLUE);
+    gradient.color_at(0.5);
+}
+
+#[test]
+fn draw() {
+    let stops = [
+        Stop::new(0., Color::rgba(255, 255, 255, 255)),
+        Stop::new(1., Color::rgba(255, 255, 255, 0)),
+    ];
+    let gradient = Gradient::linear(0., 0., 4., 0., &stops);
+    let mut buf = [0u32; 4];
+    let mut canvas = Canvas::new(&mut buf, (4, 1), (4, 1));
+    canvas.borrowed().rect(0, 0, 4, 1, &gradient);
+    // Translucent stops are blended over the black canvas
+    assert_eq!(buf, [0xdfdfdf, 0x9f9f9f, 0x606060, 0x202020]);
+}
@@ -0,0 +1,82 @@
+use framebrush::{Canvas, Image};
+
+fn rows<B: AsRef<[u32]>>(image: &Image<u32, B>) -> Vec<Vec<u32>> {
+    image.rows().map(|row| row.copied().collect()).collect()
+}
+
+fn pixels(canvas: &Canvas<u32, &mut [u32]>) -> Vec<Vec<u32>> {
+    rows(&canvas.surface())
+}
+
+/// A 3x2 image in a buffer with a stride of 4
+const DATA: [u32; 7] = [1, 2, 3, 0, 4, 5, 6];
+
+#[test]
+fn stride_and_regions() {
+    let image = Image::with_stride(&DATA[..], 3, 2, 4);
+    assert_eq!(rows(&image), [[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(image.get(2, 1), Some(&6));
+    assert_eq!(image.get(3, 0), None);
+
+    let region = image.region(1, 0, 2, 2);
+    assert_eq!((region.size(), region.stride()), ((2, 2), 4));
+    assert_eq!(rows(&region), [[2, 3], [5, 6]]);
+    // Regions are clipped to the image
+    assert_eq!(rows(&image.region(2, 1, 5, 5)), [[6]]);
+    assert!(image.region(3, 0, 1, 1).is_empty());
+}
+
+#[test]
+fn flipping() {
+    let image = Image::with_stride(&DATA[..], 3, 2, 4);
+    assert_eq!(rows(&image.clone().flipped_h()), [[3, 2, 1], [6, 5, 4]]);
+    assert_eq!(rows(&image.clone().flipped_v()), [[4, 5, 6], [1, 2, 3]]);
+    let both = image.flipped_h().flipped_v();
+    assert_eq!(rows(&both), [[6, 5, 4], [3, 2, 1]]);
+    // Regions are in displayed coordinates and stay flipped
+    assert_eq!(rows(&both.region(0, 1, 2, 1)), [[3, 2]]);
+    assert_eq!(rows(&both.to_owned_image()), [[6, 5, 4], [3, 2, 1]]);
+    assert!(!both.to_owned_image().is_flipped_h());
+    assert_eq!(rows(&both.clone().flipped_h().flipped_h()), rows(&both));
+}
+
+#[test]
+fn scaled() {
+    let image = Image::with_stride(&DATA[..], 3, 2, 4);
+    assert_eq!(
+        rows(&image.scaled(6, 2)),
+        [[1, 1, 2, 2, 3, 3], [4, 4, 5, 5, 6, 6]]
+    );
+    assert_eq!(rows(&image.scaled(2, 1)), [[1, 2]]);
+    assert!(image.scaled(0, 5).is_empty());
+    assert!(Image::new(&[0u32; 0][..], 0, 0).scaled(4, 4).is_empty());
+}
+
+#[test]
+fn draw() {
+    let image = Image::new([1u32, 2, 3, 4], 2, 2).flipped_h();
+    let mut buf = [0u32; 9];
+    let mut canvas = Canvas::new(&mut buf, (3, 3), (3, 3));
+    canvas.borrowed().draw(1, 1, &image);
+    assert_eq!(buf, [0, 0, 0, 0, 2, 1, 0, 4, 3]);
+}
+
+#[test]
+fn surface() {
+    let mut buf = [1u32, 2, 3, 4, 5, 6];
+    let canvas = Canvas::new(&mut buf[..], (3, 2), (6, 4));
+    assert_eq!(canvas.surface().size(), (3, 2));
+    assert_eq!(pixels(&canvas), [[1, 2, 3], [4, 5, 6]]);
+
+    // A buffer that is too short for the surface only shows its complete rows
+    let mut buf = [1u32, 2, 3, 4, 5];
+    let canvas = Canvas::new(&mut buf[..], (3, 2), (3, 2));
+    assert_eq!(pixels(&canvas), [[1, 2, 3]]);
+    let mut buf = [1u32, 2];
+    let canvas = Canvas::new(&mut buf[..], (3, 2), (3, 2));
+    assert!(canvas.surface().is_empty());
+
+    let mut buf = [0u32; 0];
+    let canvas = Canvas::new(&mut buf[..], (0, 0), (3, 2));
+    assert!(canvas.surface().is_empty());
+}
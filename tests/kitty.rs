@@ -0,0 +1,45 @@
+use framebrush::{write_kitty, Color, Image, KittyOptions, SliceSink};
+
+#[test]
+fn rgb() {
+    let mut out = Vec::new();
+    let image = Image::new([0xff0000u32, 0x0080ff], 2, 1);
+    write_kitty(&mut out, &image, KittyOptions::default()).unwrap();
+    assert_eq!(out, b"\x1b_Ga=T,f=24,s=2,v=1,q=2,m=0;/wAAAID/\x1b\\");
+}
+
+#[test]
+fn rgba() {
+    let mut buf = [0; 64];
+    let mut sink = SliceSink::new(&mut buf);
+    let image = Image::new([Color::rgba(1, 2, 3, 4)], 1, 1);
+    write_kitty(&mut sink, &image, KittyOptions { alpha: true }).unwrap();
+    assert_eq!(
+        sink.written(),
+        b"\x1b_Ga=T,f=32,s=1,v=1,q=2,m=0;AQIDBA==\x1b\\"
+    );
+}
+
+#[test]
+fn chunks() {
+    let data: Vec<Color> = (0..1025)
+        .map(|i| Color::rgb((i % 256) as u8, (i / 256) as u8, 7))
+        .collect();
+    let mut out = Vec::new();
+    write_kitty(
+        &mut out,
+        &Image::new(data, 1025, 1),
+        KittyOptions::default(),
+    )
+    .unwrap();
+    let out = String::from_utf8(out).unwrap();
+
+    let chunks: Vec<&str> = out.split_terminator("\x1b\\").collect();
+    assert_eq!(chunks.len(), 2);
+    let first = chunks[0]
+        .strip_prefix("\x1b_Ga=T,f=24,s=1025,v=1,q=2,m=1;")
+        .unwrap();
+    assert_eq!(first.len(), 4096);
+    assert!(first.starts_with("AAAHAQAH") && first.ends_with("/gMH/wMH"));
+    assert_eq!(chunks[1], "\x1b_Gm=0;AAQH");
+}
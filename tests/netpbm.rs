@@ -0,0 +1,121 @@
+use framebrush::{
+    read_ppm, write_pam, write_pgm, write_ppm, BufferFull, Canvas, Color, DecodeError, FmtSink,
+    Image, IoSink, PnmEncoding, SliceSink, RED,
+};
+
+#[test]
+fn ppm() {
+    let image = Image::new([0xff0000u32, 0x00ff80, 0x000000, 0xffffff], 2, 2);
+    let mut out = Vec::new();
+    write_ppm(&mut out, &image, PnmEncoding::Binary).unwrap();
+    let mut expected = b"P6\n2 2\n255\n".to_vec();
+    expected.extend_from_slice(&[255, 0, 0, 0, 255, 128, 0, 0, 0, 255, 255, 255]);
+    assert_eq!(out, expected);
+
+    let mut text = FmtSink(String::new());
+    write_ppm(&mut text, &image, PnmEncoding::Ascii).unwrap();
+    assert_eq!(
+        text.0,
+        "P3\n2 2\n255\n255 0 0 0 255 128 0 0 0 255 255 255\n"
+    );
+}
+
+#[test]
+fn read() {
+    let image = Image::new([0xff0000u32, 0x00ff80, 0x000000, 0xffffff], 2, 2);
+    let mut out = Vec::new();
+    write_ppm(&mut out, &image, PnmEncoding::Binary).unwrap();
+    let read = read_ppm(&out).unwrap();
+    assert_eq!(read.size(), (2, 2));
+    assert!(read.rows().flatten().map(|c| c.to_u32()).eq(image.data));
+
+    let commented = b"P6 # size\n1\t1 255\n\x01\x02\x03";
+    assert_eq!(read_ppm(commented).unwrap().data, [Color::rgb(1, 2, 3)]);
+
+    assert_eq!(
+        read_ppm(b"P3\n1 1\n255\n").err(),
+        Some(DecodeError::InvalidMagic)
+    );
+    assert_eq!(
+        read_ppm(b"P6\n1 x\n").err(),
+        Some(DecodeError::InvalidHeader)
+    );
+    // Every field follows a separator, and a whitespace byte follows the maximum value
+    for header in [
+        &b"P612 1 255\n\0\0\0"[..],
+        b"P6 1 1 255",
+        b"P6 1 1 255X\0\0\0",
+    ] {
+        assert_eq!(read_ppm(header).err(), Some(DecodeError::InvalidHeader));
+    }
+    assert_eq!(
+        read_ppm(b"P6\n1 1\n65535\n").err(),
+        Some(DecodeError::Unsupported)
+    );
+    assert_eq!(
+        read_ppm(b"P6\n2 1\n255\n\0\0\0").err(),
+        Some(DecodeError::Truncated)
+    );
+    let huge = b"P6\n99999999999 99999999999\n255\n";
+    assert_eq!(read_ppm(huge).err(), Some(DecodeError::InvalidHeader));
+}
+
+#[test]
+fn ascii_lines_are_short() {
+    let image = Image::new([0xffffffu32; 40], 40, 1);
+    let mut text = FmtSink(String::new());
+    write_ppm(&mut text, &image, PnmEncoding::Ascii).unwrap();
+    assert!(text.0.lines().all(|line| line.len() <= 70));
+    assert_eq!(text.0.split_ascii_whitespace().count(), 4 + 40 * 3);
+}
+
+#[test]
+fn pgm() {
+    let image = Image::new([Color::WHITE, Color::rgb(255, 0, 0), Color::BLACK], 3, 1);
+    let mut out = Vec::new();
+    write_pgm(&mut out, &image, PnmEncoding::Binary).unwrap();
+    assert_eq!(out, b"P5\n3 1\n255\n\xff\x4d\x00");
+
+    let mut text = FmtSink(String::new());
+    write_pgm(&mut text, &image, PnmEncoding::Ascii).unwrap();
+    assert_eq!(text.0, "P2\n3 1\n255\n255 77 0\n");
+}
+
+#[test]
+fn pam_keeps_alpha() {
+    let image = Image::new([Color::rgba(1, 2, 3, 4)], 1, 1);
+    let mut out = Vec::new();
+    write_pam(&mut out, &image).unwrap();
+    assert_eq!(
+        out,
+        b"P7\nWIDTH 1\nHEIGHT 1\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n\x01\x02\x03\x04"
+    );
+}
+
+#[test]
+fn sinks() {
+    let mut buf = vec![0u32; 4 * 2];
+    let mut canvas = Canvas::new(&mut buf, (4, 2), (2, 1));
+    canvas.borrowed().draw(1, 0, &RED);
+
+    let mut bytes = [0; 64];
+    let mut sink = SliceSink::new(&mut bytes);
+    write_ppm(&mut sink, &canvas.surface(), PnmEncoding::Binary).unwrap();
+    assert_eq!(sink.len(), 11 + 4 * 2 * 3);
+    assert_eq!(&sink.written()[11..17], &[0, 0, 0, 0, 0, 0]);
+    assert_eq!(&sink.written()[17..23], &[255, 0, 0, 255, 0, 0]);
+
+    let mut small = [0; 16];
+    assert_eq!(
+        write_ppm(
+            &mut SliceSink::new(&mut small),
+            &canvas.surface(),
+            PnmEncoding::Binary
+        ),
+        Err(BufferFull)
+    );
+
+    let mut io = IoSink(Vec::new());
+    write_ppm(&mut io, &canvas.surface(), PnmEncoding::Binary).unwrap();
+    assert_eq!(io.0, sink.written());
+}
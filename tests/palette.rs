@@ -0,0 +1,73 @@
+use framebrush::{Canvas, Color, Cycle, Index, Palette, Rgb565};
+
+const COLORS: [Color; 4] = [
+    Color::BLACK,
+    Color::rgb(255, 0, 0),
+    Color::rgb(0, 255, 0),
+    Color::rgb(0, 0, 255),
+];
+
+#[test]
+fn draw_and_resolve() {
+    let palette = Palette::new(COLORS);
+    let mut indexed = [0u8; 4 * 2];
+    let mut canvas = Canvas::new(&mut indexed, (4, 2), (4, 2));
+    canvas
+        .borrowed()
+        .rect(1, 0, 2, 1, &palette.brush(Color::rgb(250, 10, 0)));
+    canvas.borrowed().line(0, 1, 3, 1, &Index(3));
+    canvas.borrowed().put(3, 0, 9);
+    assert_eq!(indexed, [0, 1, 1, 9, 3, 3, 3, 3]);
+
+    // Indices outside of the palette resolve to black
+    let mut rgb = [0xffffffu32; 8];
+    palette.resolve(&indexed, &mut rgb);
+    assert_eq!(rgb, [0, 0xff0000, 0xff0000, 0, 0xff, 0xff, 0xff, 0xff]);
+
+    let mut rgb565 = [Rgb565(0); 4];
+    palette.resolve(&indexed, &mut rgb565);
+    assert_eq!(
+        rgb565,
+        [Rgb565(0), Rgb565(0xf800), Rgb565(0xf800), Rgb565(0)]
+    );
+}
+
+#[test]
+fn lookup() {
+    let palette = Palette::new(&COLORS[..]);
+    assert_eq!(palette.len(), 4);
+    assert_eq!(palette.get(2), Some(Color::rgb(0, 255, 0)));
+    assert_eq!(palette.get(4), None);
+    assert_eq!(palette.nearest(Color::rgb(20, 30, 200)), 3);
+    assert_eq!(palette.nearest(Color::rgb(40, 40, 40)), 0);
+    assert!(Palette::new(&[][..]).is_empty());
+}
+
+#[test]
+fn cycling() {
+    let cycle = Cycle {
+        start: 1,
+        end: 3,
+        rate: 2.,
+    };
+    // After half a second the range has moved by one step
+    assert_eq!(cycle.remap(1, 0.), 1);
+    assert_eq!(cycle.remap(2, 0.5), 1);
+    assert_eq!(cycle.remap(1, 0.5), 3);
+    assert_eq!(cycle.remap(1, 1.5), 1);
+    assert_eq!(cycle.remap(0, 0.5), 0);
+    let backwards = Cycle { rate: -2., ..cycle };
+    assert_eq!(backwards.remap(3, 0.5), 1);
+
+    let palette = Palette::new(COLORS);
+    let mut rgb = [0u32; 4];
+    palette.resolve_cycled(&[0, 1, 2, 3], &mut rgb, &[cycle], 0.5);
+    assert_eq!(rgb, [0, 0xff, 0xff0000, 0xff00]);
+
+    // Rotating the palette itself gives the same colors
+    let mut rotated = Palette::new(COLORS);
+    rotated.rotate(1, 3, 1);
+    let mut rotated_rgb = [0u32; 4];
+    rotated.resolve(&[0, 1, 2, 3], &mut rotated_rgb);
+    assert_eq!(rotated_rgb, rgb);
+}
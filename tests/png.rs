@@ -0,0 +1,174 @@
+use framebrush::{write_png, write_png_indexed, Color, DecodeError, Image, Palette, Png, PngColor};
+
+/// 3x2 RGB image stored uncompressed, with the Sub filter on the first row and Paeth on the second
+const STORED_RGB: [u8; 88] = [
+    0x89, 0x50, 0x4e, 0x47, 0xd, 0xa, 0x1a, 0xa, 0x0, 0x0, 0x0, 0xd, 0x49, 0x48, 0x44, 0x52, 0x0,
+    0x0, 0x0, 0x3, 0x0, 0x0, 0x0, 0x2, 0x8, 0x2, 0x0, 0x0, 0x0, 0x12, 0x16, 0xf1, 0x4d, 0x0, 0x0,
+    0x0, 0x1f, 0x49, 0x44, 0x41, 0x54, 0x78, 0x1, 0x1, 0x14, 0x0, 0xeb, 0xff, 0x1, 0xa, 0x14, 0x1e,
+    0x5, 0x5, 0x5, 0x5, 0x5, 0x5, 0x4, 0x1, 0x1, 0x1, 0x0, 0x0, 0x0, 0x2, 0x2, 0x2, 0x6, 0x2d, 0x0,
+    0x69, 0x31, 0xe0, 0xfe, 0xde, 0x0, 0x0, 0x0, 0x0, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60,
+    0x82,
+];
+
+/// 4x1 gray image with 2 bits per pixel where the brightest value is transparent, compressed with fixed Huffman codes
+const FIXED_GRAY2: [u8; 81] = [
+    0x89, 0x50, 0x4e, 0x47, 0xd, 0xa, 0x1a, 0xa, 0x0, 0x0, 0x0, 0xd, 0x49, 0x48, 0x44, 0x52, 0x0,
+    0x0, 0x0, 0x4, 0x0, 0x0, 0x0, 0x1, 0x2, 0x0, 0x0, 0x0, 0x0, 0x96, 0xe7, 0x48, 0xb0, 0x0, 0x0,
+    0x0, 0x2, 0x74, 0x52, 0x4e, 0x53, 0x0, 0x3, 0xef, 0x9a, 0x9c, 0x82, 0x0, 0x0, 0x0, 0xa, 0x49,
+    0x44, 0x41, 0x54, 0x78, 0xda, 0x63, 0x90, 0x6, 0x0, 0x0, 0x1d, 0x0, 0x1c, 0x23, 0x7c, 0x8f,
+    0xac, 0x0, 0x0, 0x0, 0x0, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+/// CRC-32 of a PNG chunk type and data
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                crc >> 1 ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn colors(png: &Png) -> Vec<Color> {
+    png.to_image::<Color>().rows().flatten().copied().collect()
+}
+
+#[test]
+fn decode_fixtures() {
+    let png = Png::decode(&STORED_RGB).unwrap();
+    assert_eq!(
+        (png.size(), png.color(), png.bit_depth()),
+        ((3, 2), PngColor::Rgb, 8)
+    );
+    assert_eq!(
+        colors(&png),
+        [
+            Color::rgb(10, 20, 30),
+            Color::rgb(15, 25, 35),
+            Color::rgb(20, 30, 40),
+            Color::rgb(11, 21, 31),
+            Color::rgb(15, 25, 35),
+            Color::rgb(22, 32, 42),
+        ]
+    );
+
+    let png = Png::decode(&FIXED_GRAY2).unwrap();
+    assert_eq!((png.color(), png.bit_depth()), (PngColor::Gray, 2));
+    assert_eq!(
+        colors(&png),
+        [
+            Color::rgb(0, 0, 0),
+            Color::rgb(85, 85, 85),
+            Color::rgb(170, 170, 170),
+            Color::rgba(255, 255, 255, 0),
+        ]
+    );
+}
+
+#[test]
+fn round_trip() {
+    // A gradient with noise, so that matches, literals and all filters are used
+    let (w, h) = (70, 40);
+    let pixels: Vec<Color> = (0..w * h)
+        .map(|i| {
+            let (x, y) = (i % w, i / w);
+            let noise = (i as u32).wrapping_mul(2_654_435_761) >> 29;
+            Color::rgba(
+                (x * 3) as u8,
+                (y * 6) as u8,
+                noise as u8 * 30,
+                (x + y) as u8,
+            )
+        })
+        .collect();
+    let image = Image::new(pixels, w, h);
+
+    for color in [
+        PngColor::Gray,
+        PngColor::GrayAlpha,
+        PngColor::Rgb,
+        PngColor::Rgba,
+    ] {
+        let mut out = Vec::new();
+        write_png(&mut out, &image, color).unwrap();
+        let png = Png::decode(&out).unwrap();
+        assert_eq!((png.size(), png.color()), ((w, h), color));
+        let expected = image.rows().flatten().map(|&c| match color {
+            PngColor::Gray => Color::rgb(c.luma(), c.luma(), c.luma()),
+            PngColor::GrayAlpha => Color::rgba(c.luma(), c.luma(), c.luma(), c.a),
+            PngColor::Rgb => Color { a: 255, ..c },
+            _ => c,
+        });
+        assert!(colors(&png).into_iter().eq(expected), "{color:?}");
+    }
+
+    // More than 256 colors fall back to RGBA
+    let mut out = Vec::new();
+    write_png(&mut out, &image, PngColor::Indexed).unwrap();
+    assert_eq!(Png::decode(&out).unwrap().color(), PngColor::Rgba);
+}
+
+#[test]
+fn indexed() {
+    let image = Image::new([0u32, 0xff0000, 0xff0000, 0x00ff00].repeat(50), 20, 10);
+    let mut out = Vec::new();
+    write_png(&mut out, &image, PngColor::Indexed).unwrap();
+    let png = Png::decode(&out).unwrap();
+    assert_eq!(png.color(), PngColor::Indexed);
+    assert_eq!(png.palette().len(), 3);
+    let mut buf = vec![0u32; 200];
+    png.decode_into(&mut buf);
+    assert_eq!(buf, image.rows().flatten().copied().collect::<Vec<_>>());
+
+    let palette = Palette::new([Color::rgba(0, 0, 0, 0), Color::rgb(255, 255, 0)]);
+    let indices = Image::new([0u8, 1, 1, 5], 2, 2);
+    let mut out = Vec::new();
+    write_png_indexed(&mut out, &indices, &palette).unwrap();
+    let png = Png::decode(&out).unwrap();
+    assert_eq!(png.palette().len(), 6);
+    assert_eq!(
+        colors(&png),
+        [
+            Color::rgba(0, 0, 0, 0),
+            Color::rgb(255, 255, 0),
+            Color::rgb(255, 255, 0),
+            Color::BLACK,
+        ]
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(Png::decode(b"BM"), Err(DecodeError::InvalidMagic));
+    assert_eq!(Png::decode(&STORED_RGB[..60]), Err(DecodeError::Truncated));
+    let mut corrupt = STORED_RGB;
+    corrupt[50] ^= 1;
+    assert_eq!(Png::decode(&corrupt), Err(DecodeError::Corrupt));
+
+    // A 16-bit RGBA header of 0xffffffff x 0xffffffff pixels, the size of the image data overflows
+    let mut huge = STORED_RGB;
+    huge[16..33].copy_from_slice(&[
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x10, 0x6, 0x0, 0x0, 0x0, 0x82, 0x66, 0xf2,
+        0x7d,
+    ]);
+    assert_eq!(Png::decode(&huge), Err(DecodeError::InvalidHeader));
+
+    // The image data of a 100x100 image behind a header that declares 1x1 pixels, the excess isn't decompressed
+    let mut bomb = Vec::new();
+    write_png(
+        &mut bomb,
+        &Image::new(vec![0u32; 100 * 100], 100, 100),
+        PngColor::Rgb,
+    )
+    .unwrap();
+    bomb[16..24].copy_from_slice(&[0, 0, 0, 1, 0, 0, 0, 1]);
+    let crc = crc32(&bomb[12..29]);
+    bomb[29..33].copy_from_slice(&crc.to_be_bytes());
+    assert_eq!(Png::decode(&bomb), Err(DecodeError::Corrupt));
+}
@@ -0,0 +1,130 @@
+use framebrush::{write_qoi, Canvas, Color, DecodeError, Image, Qoi, QoiOptions};
+
+const RED: Color = Color::rgb(255, 0, 0);
+
+/// Every kind of chunk: diff, run, diff, RGBA, index, luma and the end marker
+const ENCODED: [u8; 33] = [
+    b'q', b'o', b'i', b'f', 0, 0, 0, 3, 0, 0, 0, 2, 4, 0, 0x5a, 0xc0, 0x6e, 0xff, 0, 0, 0xff, 0x80,
+    0x32, 0xaa, 0x46, 0, 0, 0, 0, 0, 0, 0, 1,
+];
+
+fn pixels() -> [Color; 6] {
+    [
+        RED,
+        RED,
+        Color::rgb(255, 1, 0),
+        Color::rgba(0, 0, 255, 128),
+        RED,
+        Color::rgb(5, 10, 8),
+    ]
+}
+
+#[test]
+fn encode() {
+    let mut out = Vec::new();
+    let options = QoiOptions {
+        alpha: true,
+        ..Default::default()
+    };
+    write_qoi(&mut out, &Image::new(pixels(), 3, 2), options).unwrap();
+    assert_eq!(out, ENCODED);
+}
+
+#[test]
+fn decode() {
+    let qoi = Qoi::parse(&ENCODED).unwrap();
+    assert_eq!(qoi.size(), (3, 2));
+    assert!(qoi.has_alpha() && !qoi.is_linear());
+    let mut buf = [Color::BLACK; 6];
+    qoi.decode_into(&mut buf).unwrap();
+    assert_eq!(buf, pixels());
+
+    // Straight into the middle of a canvas, everything else stays untouched
+    let mut buf = [0u32; 5 * 4];
+    let mut canvas = Canvas::new(&mut buf[..], (5, 4), (5, 4));
+    qoi.decode_to_canvas(&mut canvas, 1, 1).unwrap();
+    assert_eq!(
+        buf,
+        [
+            0, 0, 0, 0, 0, //
+            0, 0xff0000, 0xff0000, 0xff0100, 0, //
+            0, 0x0000ff, 0xff0000, 0x050a08, 0, //
+            0, 0, 0, 0, 0,
+        ]
+    );
+}
+
+#[test]
+fn round_trip() {
+    // Long runs, small and large steps and changing alpha
+    let pixels: Vec<Color> = (0..64 * 16u32)
+        .map(|i| match i % 300 {
+            0..=99 => Color::rgb(1, 2, 3),
+            n => {
+                let v = i.wrapping_mul(2_654_435_761) >> 24;
+                Color::rgba(
+                    n as u8,
+                    (v % 7) as u8,
+                    v as u8,
+                    if n % 3 == 0 { 255 } else { n as u8 },
+                )
+            }
+        })
+        .collect();
+    let image = Image::new(pixels, 64, 16);
+    for alpha in [false, true] {
+        let mut out = Vec::new();
+        write_qoi(
+            &mut out,
+            &image,
+            QoiOptions {
+                alpha,
+                linear: true,
+            },
+        )
+        .unwrap();
+        assert!(out.len() < 64 * 16 * 4);
+        let qoi = Qoi::parse(&out).unwrap();
+        assert_eq!((qoi.has_alpha(), qoi.is_linear()), (alpha, true));
+        let decoded = qoi.to_image::<Color>().unwrap();
+        let expected = image
+            .rows()
+            .flatten()
+            .map(|&c| if alpha { c } else { Color { a: 255, ..c } });
+        assert!(decoded.rows().flatten().copied().eq(expected));
+    }
+}
+
+#[test]
+fn errors() {
+    assert_eq!(Qoi::parse(b"\x89PNG"), Err(DecodeError::InvalidMagic));
+    assert_eq!(Qoi::parse(&ENCODED[..10]), Err(DecodeError::Truncated));
+    let mut header = ENCODED;
+    header[12] = 5;
+    assert_eq!(Qoi::parse(&header), Err(DecodeError::InvalidHeader));
+
+    // A lone header that declares a 65535x65535 image must not allocate the pixels
+    let mut huge = [0; 14];
+    huge[..4].copy_from_slice(b"qoif");
+    huge[4..12].copy_from_slice(&[0, 0, 0xff, 0xff, 0, 0, 0xff, 0xff]);
+    huge[12] = 4;
+    assert_eq!(Qoi::parse(&huge), Err(DecodeError::Truncated));
+    huge[4..12].fill(0);
+    assert_eq!(
+        Qoi::parse(&huge)
+            .unwrap()
+            .to_image::<Color>()
+            .unwrap()
+            .size(),
+        (0, 0)
+    );
+
+    // The pixels before the error are still decoded
+    let qoi = Qoi::parse(&ENCODED[..20]).unwrap();
+    let mut decoded = 0;
+    assert_eq!(
+        qoi.decode_with(|_, _, _| decoded += 1),
+        Err(DecodeError::Truncated)
+    );
+    assert_eq!(decoded, 3);
+}
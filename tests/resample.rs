@@ -0,0 +1,66 @@
+use framebrush::{Canvas, Filter, Image};
+
+#[test]
+fn upscale() {
+    let image = Image::new([0u32, 0xff, 0xff00, 0xff0000], 2, 2);
+    let mut nearest = [0u32; 16];
+    image.resample_into(&mut nearest, (4, 4), Filter::Nearest);
+    assert_eq!(nearest[..4], [0, 0, 0xff, 0xff]);
+    assert_eq!(nearest[12..], [0xff00, 0xff00, 0xff0000, 0xff0000]);
+
+    // Bilinear blends the neighbours and clamps at the edges
+    let row = Image::new([0u32, 0xff], 2, 1);
+    let mut bilinear = [0u32; 4];
+    row.resample_into(&mut bilinear, (4, 1), Filter::Bilinear);
+    assert_eq!(bilinear, [0, 0x40, 0xbf, 0xff]);
+
+    // Catmull-Rom keeps a flat image flat
+    let flat = Image::new([0x808080u32; 4], 2, 2);
+    let mut bicubic = [0u32; 9];
+    flat.resample_into(&mut bicubic, (3, 3), Filter::Bicubic);
+    assert_eq!(bicubic, [0x808080; 9]);
+}
+
+#[test]
+fn downscale() {
+    let image = Image::new([0u32, 0xff, 0xff, 0xff, 0xff0000, 0xff0000, 0, 0], 4, 2);
+    assert_eq!(image.sample(0, 0, (2, 1), Filter::Area), 0x800040);
+    assert_eq!(image.sample(1, 0, (2, 1), Filter::Area), 0x80);
+
+    // A third of the source pixel is covered by each destination pixel
+    let row = Image::new([0u32, 0xff, 0], 3, 1);
+    let mut area = [0u32; 2];
+    row.resample_into(&mut area, (2, 1), Filter::Area);
+    assert_eq!(area, [0x55, 0x55]);
+}
+
+#[test]
+fn empty() {
+    let empty = Image::new([0u32; 0], 0, 0);
+    let mut dst = [7u32; 4];
+    empty.resample_into(&mut dst, (2, 2), Filter::Bilinear);
+    assert_eq!(dst, [7; 4]);
+
+    let image = Image::new([1u32; 4], 2, 2);
+    image.resample_into(&mut dst, (0, 2), Filter::Nearest);
+    image.resample_into(&mut dst, (2, 0), Filter::Nearest);
+    assert_eq!(dst, [7; 4]);
+
+    // A minimized window has a 0x0 surface
+    let mut buf = [0u32; 0];
+    let mut canvas = Canvas::new(&mut buf[..], (0, 0), (2, 2));
+    canvas.present(&image, Filter::Area);
+}
+
+#[test]
+fn present() {
+    let src = Image::new([0u32, 0xff, 0xff00, 0xff0000], 2, 2);
+    let mut buf = [0u32; 16];
+    let mut canvas = Canvas::new(&mut buf[..], (4, 4), (2, 2));
+    canvas.present(&src, Filter::Nearest);
+    assert_eq!(buf[4..8], [0, 0, 0xff, 0xff]);
+
+    let mut small = [0u32; 1];
+    Canvas::new(&mut small[..], (1, 1), (2, 2)).present(&src, Filter::Area);
+    assert_eq!(small, [0x404040]);
+}
@@ -0,0 +1,53 @@
+use framebrush::{write_sixel, write_sixel_indexed, Color, Image, Palette};
+
+#[test]
+fn bands() {
+    let (r, b, t) = (
+        Color::rgb(255, 0, 0),
+        Color::rgb(0, 0, 255),
+        Color::TRANSPARENT,
+    );
+    #[rustfmt::skip]
+    let data = [
+        r, r, b,
+        r, r, b,
+        r, r, r,
+        r, r, r,
+        r, r, r,
+        r, r, r,
+        b, t, t,
+    ];
+    let mut out = Vec::new();
+    write_sixel(&mut out, &Image::new(data, 3, 7), 256).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "\x1bP0;1;0q\"1;1;3;7#0;2;0;0;100#1;2;100;0;0#0??B$#1~~{-#0@\x1b\\"
+    );
+}
+
+#[test]
+fn indexed() {
+    let mut data = [0u8; 10];
+    data[9] = 5;
+    let palette = Palette::new([Color::WHITE]);
+    let mut out = Vec::new();
+    write_sixel_indexed(&mut out, &Image::new(data, 10, 1), &palette).unwrap();
+    // Runs are repeated and the index outside of the palette is transparent
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "\x1bP0;1;0q\"1;1;10;1#0;2;100;100;100#0!9@\x1b\\"
+    );
+}
+
+#[test]
+fn quantized() {
+    let data: Vec<u32> = (0..64).map(|i| (i * 4) << 8).collect();
+    let mut out = Vec::new();
+    write_sixel(&mut out, &Image::new(data, 8, 8), 4).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let registers = (0..256)
+        .filter(|i| out.contains(&format!("#{i};2;")))
+        .count();
+    assert_eq!(registers, 4);
+    assert!(out.ends_with("\x1b\\"));
+}
@@ -0,0 +1,127 @@
+use framebrush::{
+    testing::{render, Snapshot, SnapshotError},
+    Image, Pixel, BLUE, GREEN, RED, WHITE, YELLOW,
+};
+
+fn snapshot() -> Snapshot {
+    Snapshot::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots"))
+}
+
+#[test]
+fn rect() {
+    let image = render((32, 24), (32, 24), 0, |canvas| {
+        canvas.rect(2, 2, 10, 6, &RED);
+        canvas.rect(8, 5, 12, 12, &GREEN);
+        canvas.rect(20, 0, 1, 24, &WHITE);
+        // Crosses the bottom right corner, which wraps around with the `wrap` feature
+        canvas.rect(28, 20, 8, 8, &BLUE);
+    });
+    snapshot().assert("rect.png", &image);
+}
+
+#[test]
+fn line() {
+    let image = render((32, 32), (32, 32), 0, |canvas| {
+        // One line into every octant from the center
+        for (i, (x, y)) in [
+            (31, 20),
+            (31, 31),
+            (20, 31),
+            (0, 31),
+            (0, 20),
+            (0, 0),
+            (20, 0),
+            (31, 0),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let color = [RED, GREEN, BLUE, YELLOW][i % 4].clone();
+            canvas.line(16, 16, x, y, &color);
+        }
+    });
+    snapshot().assert("line.png", &image);
+}
+
+/// Two diagonals and a pixel outside of the canvas on a gray background, the scene of `pixel.ppm`
+fn pixel_scene() -> Image<u32, Vec<u32>> {
+    render((8, 8), (8, 8), 0x202020, |canvas| {
+        for i in 0..8 {
+            canvas.draw(i, i, &Pixel::new(0xff8000));
+            canvas.draw(7 - i, i, &Pixel::new(0x0080ff));
+        }
+        canvas.draw(8, 3, &Pixel::new(0xffffff));
+    })
+}
+
+#[test]
+fn pixel() {
+    snapshot().assert("pixel.ppm", &pixel_scene());
+}
+
+#[test]
+fn scaling() {
+    let scene = |canvas: &mut framebrush::Canvas<u32, &mut [u32]>| {
+        canvas.rect(1, 1, 4, 3, &RED);
+        canvas.line(0, 9, 9, 0, &WHITE);
+        canvas.draw(7, 7, &Pixel::new(0x00ff00));
+    };
+    // Every canvas pixel covers 4x4 surface pixels
+    snapshot().assert("scaling_integer.png", &render((40, 40), (10, 10), 0, scene));
+    // Canvas pixels are rounded to 3 or 4 surface pixels wide and 2 or 3 high
+    snapshot().assert(
+        "scaling_fractional.png",
+        &render((35, 25), (10, 10), 0, scene),
+    );
+}
+
+#[test]
+fn harness() {
+    if Snapshot::is_blessing() {
+        return;
+    }
+    let dir = std::env::temp_dir().join(format!("framebrush-snapshots-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::copy(snapshot_path("pixel.ppm"), dir.join("pixel.ppm")).unwrap();
+    let snapshot = Snapshot::new(&dir);
+
+    let mut image = pixel_scene();
+    snapshot.compare("pixel.ppm", &image).unwrap();
+
+    image.data[1] = 0x232020;
+    image.data[9] = 0x000000;
+    match snapshot.compare("pixel.ppm", &image) {
+        Err(SnapshotError::Mismatch {
+            pixels: 2,
+            max_difference: 0xff,
+            diff,
+        }) => {
+            let diff = framebrush::Png::decode(&std::fs::read(diff).unwrap()).unwrap();
+            assert_eq!(diff.pixel(1, 1), framebrush::Color::rgb(255, 0, 0));
+            assert_eq!(diff.pixel(2, 1), framebrush::Color::rgb(10, 10, 10));
+        }
+        result => panic!("{result:?}"),
+    }
+    assert!(dir.join("pixel.actual.png").exists());
+
+    // Only the pixel that is off by 3 is within the tolerance
+    match snapshot.with_tolerance(3).compare("pixel.ppm", &image) {
+        Err(SnapshotError::Mismatch { pixels: 1, .. }) => {}
+        result => panic!("{result:?}"),
+    }
+
+    let small = Image::new(vec![0u32; 4], 2, 2);
+    assert!(matches!(
+        Snapshot::new(&dir).compare("pixel.ppm", &small),
+        Err(SnapshotError::SizeMismatch { actual: (2, 2), .. })
+    ));
+    assert!(matches!(
+        Snapshot::new(&dir).compare("missing.png", &small),
+        Err(SnapshotError::Missing(_))
+    ));
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+fn snapshot_path(name: &str) -> std::path::PathBuf {
+    std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots")).join(name)
+}
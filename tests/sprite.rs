@@ -0,0 +1,99 @@
+use framebrush::{
+    AnimatedSprite, BlendSpace, Canvas, Color, Image, Sprite, SpriteSheet, Transparency,
+};
+
+#[test]
+fn transparency() {
+    let image = Image::new(&[1u32, 0xff00ff, 2, 3][..], 2, 2);
+    let mut buf = [9u32; 9];
+    let mut canvas = Canvas::new(&mut buf, (3, 3), (3, 3));
+    canvas.borrowed().draw(1, 1, &Sprite::new(image.clone()));
+    assert_eq!(buf, [9, 9, 9, 9, 1, 0xff00ff, 9, 2, 3]);
+
+    let mut buf = [9u32; 4];
+    let mut canvas = Canvas::new(&mut buf, (2, 2), (2, 2));
+    let keyed = Sprite::new(image).with_transparency(Transparency::Key(0xff00ff));
+    canvas.borrowed().draw(0, 0, &keyed);
+    assert_eq!(buf, [1, 9, 2, 3]);
+}
+
+#[test]
+fn alpha() {
+    let pixels = [
+        Color::rgba(255, 0, 0, 0x80),
+        Color::rgba(0, 255, 0, 0),
+        Color::rgba(0, 255, 0, 255),
+    ];
+    let sprite = Sprite::new(Image::new(&pixels[..], 3, 1))
+        .with_transparency(Transparency::Alpha(BlendSpace::Srgb));
+
+    // The alpha of the `Color` pixels is kept on a `u32` canvas, which has no alpha channel
+    let mut buf = [0x0000ffu32; 3];
+    let mut canvas = Canvas::new(&mut buf, (3, 1), (3, 1));
+    canvas.borrowed().draw(0, 0, &sprite);
+    assert_eq!(buf, [0x80007f, 0x0000ff, 0x00ff00]);
+
+    let mut colors = [Color::rgba(0, 0, 255, 255); 3];
+    let mut canvas = Canvas::new(&mut colors, (3, 1), (3, 1));
+    let same_format: Sprite<Color> =
+        Sprite::new(sprite.image.clone()).with_transparency(sprite.transparency);
+    canvas.borrowed().draw(0, 0, &same_format);
+    assert_eq!(colors[0], Color::rgba(128, 0, 127, 255));
+
+    // `u32` pixels are always opaque
+    let opaque = Sprite::new(Image::new(&[0x80ff0000u32][..], 1, 1))
+        .with_transparency(Transparency::Alpha(BlendSpace::Srgb));
+    let mut buf = [0x0000ffu32];
+    let mut canvas = Canvas::new(&mut buf, (1, 1), (1, 1));
+    canvas.borrowed().draw(0, 0, &opaque);
+    assert_eq!(buf, [0xff0000]);
+}
+
+#[test]
+fn sheet() {
+    // Three 1x2 frames in a 2x4 image, the last cell is incomplete
+    let data = [1u32, 2, 3, 4, 5, 6, 7, 8];
+    let sheet = SpriteSheet::new(Image::new(&data[..], 2, 4), (1, 2), &[0.5, 1.]);
+    assert_eq!(sheet.columns(), 2);
+    assert_eq!(sheet.len(), 4);
+    let frame = sheet.frame(1).unwrap();
+    assert_eq!(
+        frame.image.rows().flatten().copied().collect::<Vec<_>>(),
+        [2, 4]
+    );
+    assert!(sheet.frame(4).is_none());
+
+    // The last duration is used for the remaining frames
+    assert_eq!(sheet.duration(3), 1.);
+    assert_eq!(sheet.total_duration(), 3.5);
+    assert_eq!(sheet.frame_index_at(0.4, false), 0);
+    assert_eq!(sheet.frame_index_at(1.6, false), 2);
+    assert_eq!(sheet.frame_index_at(10., false), 3);
+    assert_eq!(sheet.frame_index_at(3.6, true), 0);
+
+    let empty = SpriteSheet::new(Image::new(&data[..], 2, 4), (0, 2), &[]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.frame_index_at(1., true), 0);
+}
+
+#[test]
+fn animated() {
+    let data = [
+        Color::rgba(255, 0, 0, 255),
+        Color::rgba(0, 0, 0, 0),
+        Color::rgba(0, 0, 0, 0),
+        Color::rgba(0, 255, 0, 255),
+    ];
+    let sheet = SpriteSheet::new(Image::new(&data[..], 4, 1), (2, 1), &[1.])
+        .with_transparency(Transparency::Alpha(BlendSpace::Srgb));
+    let draw = |time| {
+        let mut buf = [0x0000ffu32; 2];
+        let mut canvas = Canvas::new(&mut buf, (2, 1), (2, 1));
+        canvas
+            .borrowed()
+            .draw(0, 0, &AnimatedSprite::new(&sheet, time, true));
+        buf
+    };
+    assert_eq!(draw(1.5), [0x0000ff, 0x00ff00]);
+    assert_eq!(draw(2.), [0xff0000, 0x0000ff]);
+}
@@ -0,0 +1,124 @@
+use framebrush::{Align, Canvas, Draw, MonoFont, Text, TextBox, VerticalAlign, FONT_8X8};
+
+struct Mark;
+
+impl Draw for Mark {
+    type T = char;
+    fn draw(&self, canvas: &mut Canvas<char, &mut [char]>, x: i32, y: i32) {
+        canvas.put(x, y, '#');
+    }
+}
+
+/// Returns the lines of a text box drawn at (10, 20) as strings with the line's pen position
+fn lines(text_box: &TextBox<MonoFont, Mark>) -> Vec<(String, i32, i32)> {
+    let mut lines: Vec<(String, i32, i32)> = Vec::new();
+    text_box.layout(10, 20, |p| match lines.last_mut() {
+        Some((line, _, y)) if *y == p.y => line.push(p.c),
+        _ => lines.push((p.c.to_string(), p.x, p.y)),
+    });
+    lines
+}
+
+fn line(text: &str, x: i32, y: i32) -> (String, i32, i32) {
+    (text.to_string(), x, y)
+}
+
+#[test]
+fn measure() {
+    let text = Text::new("", &FONT_8X8, &Mark);
+    assert_eq!(text.measure("abc"), (24, 8));
+    assert_eq!(text.measure("abc\nde\n"), (24, 24));
+    let text = text.with_spacing(2, 3).with_scale(2);
+    assert_eq!(text.measure("abc"), (52, 16));
+    assert_eq!(text.measure("a\nb"), (16, 35));
+    // Characters without a glyph are measured with the fallback glyph
+    assert_eq!(text.measure("\u{e9}"), (16, 16));
+}
+
+#[test]
+fn word_wrap() {
+    let text = Text::new("ab cd  efghij\nk", &FONT_8X8, &Mark);
+    assert_eq!(
+        lines(&TextBox::new(text, (40, 100))),
+        [
+            line("ab cd", 10, 20),
+            line("efghi", 10, 28),
+            line("j", 10, 36),
+            line("k", 10, 44),
+        ]
+    );
+}
+
+#[test]
+fn leading_spaces() {
+    // Indentation is kept while it fits, but never wraps into an empty line
+    let text = Text::new("  ab", &FONT_8X8, &Mark);
+    assert_eq!(
+        lines(&TextBox::new(text, (32, 100))),
+        [line("  ab", 10, 20)]
+    );
+    let text = Text::new("    a\n   bc d", &FONT_8X8, &Mark);
+    assert_eq!(
+        lines(&TextBox::new(text, (16, 100))),
+        [line("a", 10, 20), line("bc", 10, 28), line("d", 10, 36)]
+    );
+
+    let mut buf = ['.'; 16 * 16];
+    let text_box = TextBox::new(Text::new("    a", &FONT_8X8, &Mark), (16, 16));
+    Canvas::new(&mut buf, (16, 16), (16, 16))
+        .borrowed()
+        .draw(0, 0, &text_box);
+    assert!(buf[..16 * 8].contains(&'#'));
+    assert!(!buf[16 * 8..].contains(&'#'));
+}
+
+#[test]
+fn alignment() {
+    let text = Text::new("ab\nabcd", &FONT_8X8, &Mark).with_spacing(0, 2);
+    let text_box = TextBox::new(text, (64, 30)).with_align(Align::Center, VerticalAlign::Middle);
+    assert_eq!(lines(&text_box), [line("ab", 34, 26), line("abcd", 26, 36)]);
+    let text = Text::new("ab\nabcd", &FONT_8X8, &Mark);
+    let text_box = TextBox::new(text, (64, 30)).with_align(Align::Right, VerticalAlign::Bottom);
+    assert_eq!(lines(&text_box), [line("ab", 58, 34), line("abcd", 42, 42)]);
+}
+
+#[test]
+fn ellipsis() {
+    // Too long for the width without wrapping
+    let text = Text::new("abcdefgh", &FONT_8X8, &Mark);
+    let text_box = TextBox::new(text, (56, 8))
+        .with_wrap(false)
+        .with_ellipsis("..");
+    assert_eq!(lines(&text_box), [line("abcde..", 10, 20)]);
+
+    // Too many lines for the height, the last visible line gets the ellipsis
+    let text = Text::new("ab cd ef", &FONT_8X8, &Mark);
+    let text_box = TextBox::new(text, (24, 20)).with_ellipsis(".");
+    assert_eq!(lines(&text_box), [line("ab", 10, 20), line("cd.", 10, 28)]);
+
+    // Without an ellipsis the text is just cut off
+    let text = Text::new("ab cd ef", &FONT_8X8, &Mark);
+    assert_eq!(
+        lines(&TextBox::new(text, (24, 20))),
+        [line("ab", 10, 20), line("cd", 10, 28)]
+    );
+}
+
+#[test]
+fn clipped_to_box() {
+    let mut buf = vec!['.'; 12 * 4];
+    let text = Text::new("W", &FONT_8X8, &Mark).with_scale(2);
+    Canvas::new(&mut buf, (12, 4), (12, 4))
+        .borrowed()
+        .draw(2, 0, &TextBox::new(text, (6, 3)));
+    let rows: Vec<String> = buf.chunks(12).map(|row| row.iter().collect()).collect();
+    assert_eq!(
+        rows,
+        [
+            "..####......",
+            "..####......",
+            "..####......",
+            "............"
+        ]
+    );
+}
@@ -0,0 +1,118 @@
+use core::f32::consts::FRAC_PI_2;
+
+use framebrush::{Affine, Canvas, Image, Sampling};
+
+fn assert_close((x, y): (f32, f32), expected: (f32, f32)) {
+    assert!(
+        (x - expected.0).abs() < 1e-5 && (y - expected.1).abs() < 1e-5,
+        "{:?} != {expected:?}",
+        (x, y)
+    );
+}
+
+#[test]
+fn affine() {
+    assert_eq!(Affine::default().apply(3., 4.), (3., 4.));
+    assert_eq!(Affine::translate(1., 2.).apply(3., 4.), (4., 6.));
+    assert_eq!(Affine::scale(2., 3.).apply(3., 4.), (6., 12.));
+    assert_eq!(Affine::shear(1., 0.).apply(3., 4.), (7., 4.));
+    // Clockwise on the screen: the x axis turns into the y axis
+    assert_close(Affine::rotate(FRAC_PI_2).apply(1., 0.), (0., 1.));
+
+    // `then` applies `self` first
+    let t = Affine::scale(2., 2.).then(Affine::translate(1., 0.));
+    assert_eq!(t.apply(1., 1.), (3., 2.));
+    let t = Affine::translate(1., 0.).then(Affine::scale(2., 2.));
+    assert_eq!(t.apply(1., 1.), (4., 2.));
+
+    let t = Affine::rotate(0.3)
+        .then(Affine::scale(2., 0.5))
+        .then(Affine::translate(5., -1.));
+    let inverse = t.invert().unwrap();
+    let (x, y) = t.apply(3., 4.);
+    assert_close(inverse.apply(x, y), (3., 4.));
+    assert_eq!(Affine::scale(0., 1.).invert(), None);
+}
+
+#[test]
+fn sample_at() {
+    let image = Image::new([0u32, 0xff, 0xff00, 0xff0000], 2, 2);
+    assert_eq!(image.sample_at(1.5, 0.2, Sampling::Nearest), Some(0xff));
+    assert_eq!(image.sample_at(2., 0., Sampling::Nearest), None);
+    assert_eq!(image.sample_at(-0.1, 0., Sampling::Bilinear), None);
+    // Pixel centers are exact, between them the colors are interpolated and the edges are clamped
+    assert_eq!(image.sample_at(0.5, 1.5, Sampling::Bilinear), Some(0xff00));
+    assert_eq!(image.sample_at(1., 0.5, Sampling::Bilinear), Some(0x80));
+    assert_eq!(image.sample_at(0.1, 1., Sampling::Bilinear), Some(0x8000));
+}
+
+#[test]
+fn blit() {
+    let image = Image::new([1u32, 2, 3, 4, 5, 6], 3, 2);
+
+    let mut buf = [0u32; 5 * 4];
+    let mut canvas = Canvas::new(&mut buf, (5, 4), (5, 4));
+    canvas
+        .borrowed()
+        .blit_transformed(1, 1, &image, Affine::IDENTITY, Sampling::Nearest);
+    assert_eq!(buf[5..10], [0, 1, 2, 3, 0]);
+    assert_eq!(buf[10..15], [0, 4, 5, 6, 0]);
+
+    // Rotating by 90 degrees around the origin turns the rows into columns left of it, from right to left
+    let mut buf = [0u32; 4 * 4];
+    let mut canvas = Canvas::new(&mut buf, (4, 4), (4, 4));
+    canvas
+        .borrowed()
+        .blit_transformed(3, 0, &image, Affine::rotate(FRAC_PI_2), Sampling::Nearest);
+    assert_eq!(
+        buf,
+        [
+            0, 4, 1, 0, //
+            0, 5, 2, 0, //
+            0, 6, 3, 0, //
+            0, 0, 0, 0, //
+        ]
+    );
+
+    // Scaling up repeats pixels
+    let mut buf = [0u32; 6 * 2];
+    let mut canvas = Canvas::new(&mut buf, (6, 2), (6, 2));
+    canvas
+        .borrowed()
+        .blit_transformed(0, 0, &image, Affine::scale(2., 1.), Sampling::Nearest);
+    assert_eq!(buf, [1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6]);
+
+    // Transformations that collapse the image draw nothing
+    let mut buf = [0u32; 4];
+    let mut canvas = Canvas::new(&mut buf, (2, 2), (2, 2));
+    canvas
+        .borrowed()
+        .blit_transformed(0, 0, &image, Affine::scale(0., 1.), Sampling::Nearest);
+    assert_eq!(buf, [0; 4]);
+}
+
+#[test]
+fn huge_scale() {
+    let image = Image::new([1u32, 2, 3, 4, 5, 6], 3, 2);
+    let mut buf = [0u32; 4 * 3];
+    let mut canvas = Canvas::new(&mut buf, (4, 3), (4, 3));
+    // Only the pixels on the canvas are sampled, all of them inside the first image pixel
+    let huge = Affine::scale(1e9, 1e9);
+    canvas
+        .borrowed()
+        .blit_transformed(-1, -1, &image, huge, Sampling::Nearest);
+    assert_eq!(buf, [1; 12]);
+
+    // Neither infinite scales nor positions at the edge of the `i32` range overflow
+    let mut buf = [0u32; 4 * 3];
+    let mut canvas = Canvas::new(&mut buf, (4, 3), (4, 3));
+    let infinite = Affine::scale(f32::INFINITY, f32::INFINITY);
+    canvas
+        .borrowed()
+        .blit_transformed(0, 0, &image, infinite, Sampling::Nearest);
+    for (x, y) in [(i32::MAX, i32::MAX), (i32::MIN, i32::MIN)] {
+        canvas
+            .borrowed()
+            .blit_transformed(x, y, &image, huge, Sampling::Nearest);
+    }
+}
@@ -0,0 +1,218 @@
+use framebrush::{Canvas, Color, FontError, GlyphCache, OutlineText, TrueTypeFont};
+
+fn be16(out: &mut Vec<u8>, values: &[i32]) {
+    for &v in values {
+        out.extend_from_slice(&(v as u16).to_be_bytes());
+    }
+}
+
+/// A simple glyph with one contour of on-curve points
+fn simple_glyph(points: &[(i32, i32)]) -> Vec<u8> {
+    let xs = points.iter().map(|p| p.0);
+    let ys = points.iter().map(|p| p.1);
+    let mut g = Vec::new();
+    be16(
+        &mut g,
+        &[1, xs.clone().min().unwrap(), ys.clone().min().unwrap()],
+    );
+    be16(
+        &mut g,
+        &[xs.clone().max().unwrap(), ys.clone().max().unwrap()],
+    );
+    be16(&mut g, &[points.len() as i32 - 1, 0]);
+    g.extend(points.iter().map(|_| 0x01));
+    let (mut x, mut y) = (0, 0);
+    for p in points {
+        be16(&mut g, &[p.0 - x]);
+        x = p.0;
+    }
+    for p in points {
+        be16(&mut g, &[p.1 - y]);
+        y = p.1;
+    }
+    g
+}
+
+/// A font with 1000 units per em where 'A' is a 1 em square, 'B' a right triangle filling half of it and
+/// 'C' a composite of the square moved right by half an em
+fn test_font() -> Vec<u8> {
+    // ARG_1_AND_2_ARE_WORDS | ARGS_ARE_XY_VALUES, glyph 1, offset (500, 0)
+    font_with_component(&[0x0003, 1, 500, 0])
+}
+
+/// Builds the test font with a composite glyph 3 made of a single component record
+fn font_with_component(record: &[i32]) -> Vec<u8> {
+    let mut composite = Vec::new();
+    be16(&mut composite, &[-1, 500, 0, 1500, 1000]);
+    be16(&mut composite, record);
+    let glyphs = [
+        Vec::new(),
+        simple_glyph(&[(0, 0), (0, 1000), (1000, 1000), (1000, 0)]),
+        simple_glyph(&[(0, 0), (0, 1000), (1000, 0)]),
+        composite,
+    ];
+
+    let mut glyf = Vec::new();
+    let mut loca = Vec::new();
+    for g in &glyphs {
+        be16(&mut loca, &[glyf.len() as i32 / 2]);
+        glyf.extend_from_slice(g);
+        if glyf.len() % 2 == 1 {
+            glyf.push(0);
+        }
+    }
+    be16(&mut loca, &[glyf.len() as i32 / 2]);
+
+    let mut head = vec![0; 54];
+    head[18..20].copy_from_slice(&1000u16.to_be_bytes());
+    let mut maxp = vec![0, 0, 0x50, 0];
+    be16(&mut maxp, &[glyphs.len() as i32]);
+    let mut hhea = vec![0; 36];
+    hhea[4..6].copy_from_slice(&800i16.to_be_bytes());
+    hhea[6..8].copy_from_slice(&(-200i16).to_be_bytes());
+    hhea[34..36].copy_from_slice(&4u16.to_be_bytes());
+    let mut hmtx = Vec::new();
+    be16(&mut hmtx, &[500, 0, 1000, 0, 1000, 0, 1500, 0]);
+
+    // Format 4 with the segments 'A'..='C' and the final 0xffff segment
+    let mut cmap = Vec::new();
+    be16(&mut cmap, &[0, 1, 3, 1, 0, 12]);
+    be16(&mut cmap, &[4, 32, 0, 4, 4, 1, 0]);
+    be16(
+        &mut cmap,
+        &[0x43, 0xffff, 0, 0x41, 0xffff, 1 - 0x41, 1, 0, 0],
+    );
+
+    let tables: [(&[u8; 4], Vec<u8>); 7] = [
+        (b"cmap", cmap),
+        (b"glyf", glyf),
+        (b"head", head),
+        (b"hhea", hhea),
+        (b"hmtx", hmtx),
+        (b"loca", loca),
+        (b"maxp", maxp),
+    ];
+    let mut font = vec![0, 1, 0, 0];
+    be16(&mut font, &[tables.len() as i32, 0, 0, 0]);
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, data) in &tables {
+        font.extend_from_slice(*tag);
+        font.extend_from_slice(&[0; 4]);
+        font.extend_from_slice(&(offset as u32).to_be_bytes());
+        font.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in &tables {
+        font.extend_from_slice(data);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+    font
+}
+
+#[test]
+fn parse() {
+    let data = test_font();
+    let font = TrueTypeFont::parse(&data).unwrap();
+    assert_eq!(font.units_per_em(), 1000);
+    assert_eq!((font.ascent(), font.descent()), (800, -200));
+    assert_eq!(font.glyph_index('A'), Some(1));
+    assert_eq!(font.glyph_index('C'), Some(3));
+    assert_eq!(font.glyph_index('D'), None);
+    assert_eq!(font.advance_width(3), 1500);
+
+    assert_eq!(TrueTypeFont::parse(b"OTTO"), Err(FontError::Unsupported));
+    assert_eq!(
+        TrueTypeFont::parse(b"PK\x03\x04"),
+        Err(FontError::InvalidMagic)
+    );
+    assert_eq!(TrueTypeFont::parse(&data[..40]), Err(FontError::Truncated));
+}
+
+#[test]
+fn rasterize() {
+    let data = test_font();
+    let font = TrueTypeFont::parse(&data).unwrap();
+
+    let square = font.rasterize(1, 10.).unwrap();
+    assert_eq!(
+        (square.width, square.height, square.offset),
+        (10, 10, (0, -10))
+    );
+    assert_eq!(square.advance, 10.);
+    assert!(square.coverage.iter().all(|&c| c == 255));
+
+    // Pixels on the diagonal are half covered, the total covered area is half of the square
+    let triangle = font.rasterize(2, 10.).unwrap();
+    let area: u32 = triangle.coverage.iter().map(|&c| c as u32).sum();
+    assert!((area as i32 - 50 * 255).abs() <= 10, "{area}");
+    assert_eq!(triangle.image().get(0, 0), Some(&128));
+    assert_eq!(triangle.image().get(0, 9), Some(&255));
+    assert_eq!(triangle.image().get(9, 9), Some(&128));
+    assert_eq!(triangle.image().get(9, 0), Some(&0));
+
+    let composite = font.rasterize(3, 10.).unwrap();
+    assert_eq!((composite.width, composite.offset), (10, (5, -10)));
+
+    let missing = font.rasterize(0, 10.).unwrap();
+    assert_eq!((missing.width, missing.height, missing.advance), (0, 0, 5.));
+}
+
+#[test]
+fn truncated_composite() {
+    // ARGS_ARE_XY_VALUES with byte arguments, but the glyph ends before them
+    let data = font_with_component(&[0x0002, 1]);
+    let font = TrueTypeFont::parse(&data).unwrap();
+    font.rasterize(3, 16.);
+}
+
+#[test]
+fn composite_bomb() {
+    // 2000 components that all reference the composite itself, followed to the maximum depth
+    let mut record = [0x0022, 3, 0].repeat(2000);
+    record[3 * 1999] = 0x0002;
+    let data = font_with_component(&record);
+    let font = TrueTypeFont::parse(&data).unwrap();
+    assert!(font.rasterize(3, 16.).is_none());
+}
+
+#[test]
+fn outline_text() {
+    let data = test_font();
+    let cache = GlyphCache::new(TrueTypeFont::parse(&data).unwrap(), 4.);
+    assert_eq!(cache.measure("AB\nA"), (8, 8));
+
+    // The baseline is at y + 3.2, rounded to 4
+    let mut buf = vec![0u32; 10 * 8];
+    Canvas::new(&mut buf, (10, 8), (10, 8)).borrowed().draw(
+        0,
+        1,
+        &OutlineText::new("A B", &cache, Color::WHITE),
+    );
+    // Glyphs are cached by glyph index, the space is the `.notdef` glyph
+    assert_eq!(cache.len(), 3);
+    let rows: Vec<String> = buf
+        .chunks(10)
+        .map(|row| {
+            row.iter()
+                .map(|&p| match p & 0xff {
+                    0 => '.',
+                    255 => '#',
+                    _ => '+',
+                })
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            "####..+...",
+            "####..#+..",
+            "####..##+.",
+            "####..###+",
+            "..........",
+            "..........",
+            "..........",
+            "..........",
+        ]
+    );
+}
@@ -0,0 +1,59 @@
+use framebrush::{Chroma, Color, FmtSink, Image, SliceSink, Y4mEncoder};
+
+#[test]
+fn yuv444() {
+    let frame = Image::new(
+        [
+            Color::WHITE,
+            Color::BLACK,
+            Color::rgb(255, 0, 0),
+            Color::rgb(0, 0, 255),
+        ],
+        2,
+        2,
+    );
+    let mut buf = [0; 128];
+    let mut video = Y4mEncoder::new(SliceSink::new(&mut buf), (2, 2))
+        .with_frame_rate(60, 1)
+        .with_chroma(Chroma::C444);
+    video.add_frame(&frame).unwrap();
+    let sink = video.finish().unwrap();
+
+    let header = b"YUV4MPEG2 W2 H2 F60:1 Ip A1:1 C444\nFRAME\n";
+    let (start, planes) = sink.written().split_at(header.len());
+    assert_eq!(start, header);
+    assert_eq!(
+        planes,
+        [235, 16, 82, 41, 128, 128, 90, 240, 128, 128, 240, 110]
+    );
+}
+
+#[test]
+fn yuv420() {
+    // Odd sizes average only the part of the 2x2 block that is inside of the frame
+    let mut frame = [Color::rgb(0, 0, 255); 3 * 3];
+    frame[2] = Color::BLACK;
+    frame[5] = Color::BLACK;
+    let mut out = Vec::new();
+    let mut video = Y4mEncoder::new(&mut out, (3, 3));
+    for _ in 0..2 {
+        video.add_frame(&Image::new(frame, 3, 3)).unwrap();
+    }
+    video.finish().unwrap();
+
+    let header = b"YUV4MPEG2 W3 H3 F30:1 Ip A1:1 C420jpeg\n";
+    assert!(out.starts_with(header));
+    let frame_len = 6 + 9 + 2 * 4;
+    assert_eq!(out.len(), header.len() + 2 * frame_len);
+    let cb = &out[header.len() + 6 + 9..][..4];
+    assert_eq!(cb, [240, 128, 240, 240]);
+}
+
+#[test]
+fn empty() {
+    let video = Y4mEncoder::new(FmtSink(String::new()), (4, 2));
+    assert_eq!(
+        video.finish().unwrap().0,
+        "YUV4MPEG2 W4 H2 F30:1 Ip A1:1 C420jpeg\n"
+    );
+}